@@ -0,0 +1,144 @@
+use crate::utils::i18n::{self, Language};
+use serde::{Deserialize, Serialize};
+
+/// The difficulty levels the player can choose from the start screen
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+    Arcade,
+}
+
+/// The gameplay values controlled by a difficulty level
+#[derive(Clone, PartialEq, Debug)]
+pub struct DifficultyPreset {
+    pub starting_lives: i32,
+    /// Range (in seconds) the random delay between alien shots is picked from
+    pub alien_shoot_cooldown: (f64, f64),
+    pub formation_speed: f32,
+    pub move_interval: f64,
+    pub player_shoot_cooldown: f64,
+    pub invincibility_duration: f64,
+    /// When false, aliens reaching the invasion line cost a life and are pushed back up
+    pub invasion_ends_game: bool,
+}
+
+impl Difficulty {
+    /// Every difficulty, in the order they are shown on the start screen
+    pub const ALL: [Difficulty; 4] = [
+        Difficulty::Easy,
+        Difficulty::Normal,
+        Difficulty::Hard,
+        Difficulty::Arcade,
+    ];
+
+    /// Returns -> the gameplay values for this difficulty
+    pub fn preset(&self) -> DifficultyPreset {
+        match self {
+            Difficulty::Easy => DifficultyPreset {
+                starting_lives: 5,
+                alien_shoot_cooldown: (1.0, 3.0),
+                formation_speed: 15.0,
+                move_interval: 0.6,
+                player_shoot_cooldown: 0.35,
+                invincibility_duration: 4.0,
+                invasion_ends_game: false,
+            },
+            Difficulty::Normal => DifficultyPreset {
+                starting_lives: 3,
+                alien_shoot_cooldown: (0.5, 2.0),
+                formation_speed: 20.0,
+                move_interval: 0.5,
+                player_shoot_cooldown: 0.5,
+                invincibility_duration: 3.0,
+                invasion_ends_game: true,
+            },
+            Difficulty::Hard => DifficultyPreset {
+                starting_lives: 2,
+                alien_shoot_cooldown: (0.3, 1.2),
+                formation_speed: 25.0,
+                move_interval: 0.4,
+                player_shoot_cooldown: 0.6,
+                invincibility_duration: 2.0,
+                invasion_ends_game: true,
+            },
+            Difficulty::Arcade => DifficultyPreset {
+                starting_lives: 3,
+                alien_shoot_cooldown: (0.4, 1.6),
+                formation_speed: 20.0,
+                move_interval: 0.5,
+                player_shoot_cooldown: 0.8,
+                invincibility_duration: 2.0,
+                invasion_ends_game: true,
+            },
+        }
+    }
+
+    /// Returns -> the name shown to the player, in the given language
+    pub fn label(&self, language: Language) -> String {
+        let key = match self {
+            Difficulty::Easy => "difficulty.easy",
+            Difficulty::Normal => "difficulty.normal",
+            Difficulty::Hard => "difficulty.hard",
+            Difficulty::Arcade => "difficulty.arcade",
+        };
+        i18n::text(language, key)
+    }
+
+    /// Returns -> the next difficulty in the selector, wrapping around at the end
+    pub fn next(&self) -> Difficulty {
+        let index = Self::ALL.iter().position(|d| d == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Returns -> the previous difficulty in the selector, wrapping around at the start
+    pub fn previous(&self) -> Difficulty {
+        let index = Self::ALL.iter().position(|d| d == self).unwrap_or(0);
+        Self::ALL[(index + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test that Normal is the default and matches the original game values
+    #[test]
+    fn test_normal_preset() {
+        assert_eq!(Difficulty::default(), Difficulty::Normal);
+
+        let preset = Difficulty::Normal.preset();
+        assert_eq!(preset.starting_lives, 3);
+        assert_eq!(preset.alien_shoot_cooldown, (0.5, 2.0));
+        assert_eq!(preset.formation_speed, 20.0);
+        assert_eq!(preset.move_interval, 0.5);
+        assert_eq!(preset.player_shoot_cooldown, 0.5);
+        assert_eq!(preset.invincibility_duration, 3.0);
+        assert!(preset.invasion_ends_game);
+    }
+
+    // Test that harder presets make aliens shoot more often
+    #[test]
+    fn test_presets_scale() {
+        let easy = Difficulty::Easy.preset();
+        let hard = Difficulty::Hard.preset();
+        assert!(easy.starting_lives > hard.starting_lives);
+        assert!(easy.alien_shoot_cooldown.1 > hard.alien_shoot_cooldown.1);
+        assert!(easy.formation_speed < hard.formation_speed);
+        assert!(!easy.invasion_ends_game);
+    }
+
+    // Test that the selector wraps around in both directions
+    #[test]
+    fn test_next_and_previous() {
+        assert_eq!(Difficulty::Easy.next(), Difficulty::Normal);
+        assert_eq!(Difficulty::Arcade.next(), Difficulty::Easy);
+        assert_eq!(Difficulty::Easy.previous(), Difficulty::Arcade);
+        for difficulty in Difficulty::ALL {
+            assert_eq!(difficulty.next().previous(), difficulty);
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use crate::utils::storage;
+use std::fmt;
+
+/// The name the decision log is saved under, next to the settings
+pub const DIRECTOR_LOG_FILE: &str = "director_log.txt";
+
+const MIN_SCALE: f64 = 0.7;
+const MAX_SCALE: f64 = 1.5;
+const STEP: f64 = 0.05;
+/// How many of the latest decisions are kept
+const MAX_DECISIONS: usize = 20;
+
+/// What the director has seen the player do during the current level
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct LevelStats {
+    pub shots_fired: u32,
+    pub hits: u32,
+    pub lives_lost: i32,
+    pub time: f64,
+}
+
+impl LevelStats {
+    /// Returns -> the share of shots that hit something, or 0.0 if nothing was fired
+    pub fn accuracy(&self) -> f64 {
+        if self.shots_fired == 0 {
+            0.0
+        } else {
+            self.hits as f64 / self.shots_fired as f64
+        }
+    }
+}
+
+/// A record of one adjustment made by the director, kept for designers to inspect
+#[derive(Clone, PartialEq, Debug)]
+pub struct DirectorDecision {
+    pub level: i32,
+    pub stats: LevelStats,
+    /// Positive when the player did well and the game was made harder
+    pub performance: i32,
+    pub fire_rate_scale: f64,
+    pub projectile_speed_scale: f64,
+    pub tempo_scale: f64,
+}
+
+impl fmt::Display for DirectorDecision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "level {}: accuracy {:.0}%, lives lost {}, cleared in {:.1}s -> performance {:+}, fire rate x{:.2}, projectile speed x{:.2}, tempo x{:.2}",
+            self.level,
+            self.stats.accuracy() * 100.0,
+            self.stats.lives_lost,
+            self.stats.time,
+            self.performance,
+            self.fire_rate_scale,
+            self.projectile_speed_scale,
+            self.tempo_scale,
+        )
+    }
+}
+
+/// Adaptive difficulty director
+/// Watches how the player performs on each level and nudges the alien fire
+/// rate, alien projectile speed and formation tempo up or down, always staying
+/// within `MIN_SCALE` and `MAX_SCALE` of the difficulty preset.
+#[derive(Clone, PartialEq, Debug)]
+pub struct DifficultyDirector {
+    pub fire_rate_scale: f64,
+    pub projectile_speed_scale: f64,
+    pub tempo_scale: f64,
+    pub stats: LevelStats,
+    /// The latest decisions, oldest first
+    pub decisions: Vec<DirectorDecision>,
+}
+
+/// Returns a director that has not adjusted anything yet
+impl Default for DifficultyDirector {
+    fn default() -> Self {
+        Self {
+            fire_rate_scale: 1.0,
+            projectile_speed_scale: 1.0,
+            tempo_scale: 1.0,
+            stats: LevelStats::default(),
+            decisions: Vec::new(),
+        }
+    }
+}
+
+impl DifficultyDirector {
+    /// Rates the player's performance on a level
+    /// Each of accuracy, lives lost and clear time adds or removes one point.
+    /// # Arguments
+    /// * `stats` - What happened during the level
+    /// # Returns -> A score from -3 (struggling) to 3 (cruising)
+    pub fn rate(stats: &LevelStats) -> i32 {
+        let mut performance = 0;
+
+        let accuracy = stats.accuracy();
+        if accuracy >= 0.5 {
+            performance += 1;
+        } else if accuracy < 0.25 {
+            performance -= 1;
+        }
+
+        if stats.lives_lost == 0 {
+            performance += 1;
+        } else if stats.lives_lost >= 2 {
+            performance -= 1;
+        }
+
+        if stats.time < 45.0 {
+            performance += 1;
+        } else if stats.time > 120.0 {
+            performance -= 1;
+        }
+
+        performance
+    }
+
+    /// Finishes a level: rates the player, adjusts the scales and records the decision
+    /// Only the latest `MAX_DECISIONS` decisions are kept.
+    /// # Arguments
+    /// * `level` - The level that was just cleared
+    /// # Returns -> The decision that was made
+    pub fn end_level(&mut self, level: i32) -> &DirectorDecision {
+        let stats = std::mem::take(&mut self.stats);
+        let performance = Self::rate(&stats);
+        let step = performance as f64 * STEP;
+
+        self.fire_rate_scale = (self.fire_rate_scale + step).clamp(MIN_SCALE, MAX_SCALE);
+        self.projectile_speed_scale =
+            (self.projectile_speed_scale + step).clamp(MIN_SCALE, MAX_SCALE);
+        self.tempo_scale = (self.tempo_scale + step).clamp(MIN_SCALE, MAX_SCALE);
+
+        let decision = DirectorDecision {
+            level,
+            stats,
+            performance,
+            fire_rate_scale: self.fire_rate_scale,
+            projectile_speed_scale: self.projectile_speed_scale,
+            tempo_scale: self.tempo_scale,
+        };
+
+        if self.decisions.len() >= MAX_DECISIONS {
+            self.decisions.remove(0);
+        }
+        self.decisions.push(decision);
+        self.decisions.last().unwrap()
+    }
+
+    /// Returns -> the kept decisions, one per line, oldest first
+    pub fn log(&self) -> String {
+        self.decisions
+            .iter()
+            .map(|decision| format!("{decision}\n"))
+            .collect()
+    }
+
+    /// Saves the decision log so designers can inspect it after playing
+    /// # Returns -> `Ok(())` on success, or the I/O error that occurred
+    pub fn save_log(&self) -> std::io::Result<()> {
+        storage::save(DIRECTOR_LOG_FILE, &self.log())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(shots_fired: u32, hits: u32, lives_lost: i32, time: f64) -> LevelStats {
+        LevelStats {
+            shots_fired,
+            hits,
+            lives_lost,
+            time,
+        }
+    }
+
+    // Test that accuracy handles levels with no shots
+    #[test]
+    fn test_accuracy() {
+        assert_eq!(stats(0, 0, 0, 0.0).accuracy(), 0.0);
+        assert_eq!(stats(10, 4, 0, 0.0).accuracy(), 0.4);
+    }
+
+    // Test the performance rating for strong and weak players
+    #[test]
+    fn test_rate() {
+        assert_eq!(DifficultyDirector::rate(&stats(20, 15, 0, 30.0)), 3);
+        assert_eq!(DifficultyDirector::rate(&stats(100, 10, 2, 200.0)), -3);
+        assert_eq!(DifficultyDirector::rate(&stats(10, 4, 1, 60.0)), 0);
+    }
+
+    // Test that a good level makes the game harder and is logged
+    #[test]
+    fn test_end_level_adjusts_and_logs() {
+        let mut director = DifficultyDirector {
+            stats: stats(20, 15, 0, 30.0),
+            ..Default::default()
+        };
+
+        let decision = director.end_level(1).clone();
+
+        assert_eq!(decision.performance, 3);
+        assert!(director.fire_rate_scale > 1.0);
+        assert!(director.projectile_speed_scale > 1.0);
+        assert!(director.tempo_scale > 1.0);
+        assert_eq!(director.stats, LevelStats::default());
+        assert_eq!(director.decisions, vec![decision.clone()]);
+        assert!(decision.to_string().contains("level 1"));
+
+        director.stats = stats(10, 1, 2, 200.0);
+        director.end_level(2);
+        let log = director.log();
+        assert_eq!(log.lines().count(), 2);
+        assert!(log.lines().nth(1).unwrap().starts_with("level 2"));
+    }
+
+    // Test that adjustments stay within bounds
+    #[test]
+    fn test_end_level_is_bounded() {
+        let mut director = DifficultyDirector::default();
+        for level in 1..100 {
+            director.stats = stats(100, 5, 3, 300.0);
+            director.end_level(level);
+        }
+        assert_eq!(director.fire_rate_scale, MIN_SCALE);
+        assert_eq!(director.tempo_scale, MIN_SCALE);
+        assert_eq!(director.decisions.len(), MAX_DECISIONS);
+        assert_eq!(director.decisions.last().unwrap().level, 99);
+
+        for level in 1..100 {
+            director.stats = stats(10, 10, 0, 10.0);
+            director.end_level(level);
+        }
+        assert_eq!(director.projectile_speed_scale, MAX_SCALE);
+    }
+}
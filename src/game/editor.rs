@@ -0,0 +1,339 @@
+use crate::game::entities::alien::{AlienFormation, AlienType, FORMATION_COLS};
+use crate::game::entities::shield::ShieldType;
+use crate::game::level::{AlienPlacement, ShieldPlacement, SHIELD_Y};
+use crate::game::level_file::{
+    LevelFile, CUSTOM_LEVEL_FILE, FIRE_RATE_RANGE, FORMATION_SPEED_RANGE, MAX_ROWS,
+};
+use crate::utils::i18n::{self, Language};
+use crate::utils::position::Position;
+use crate::utils::storage;
+
+/// Number of alien rows the editor grid offers
+pub const EDITOR_ROWS: usize = MAX_ROWS;
+/// Number of positions a shield can be placed at
+pub const SHIELD_COLUMNS: usize = 18;
+/// Width of a shield, used to stop shields overlapping
+const SHIELD_WIDTH: f32 = 100.0;
+
+/// What placing at the cursor does in the level editor
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum EditorTool {
+    Alien(AlienType),
+    Shield(ShieldType),
+    Erase,
+}
+
+impl Default for EditorTool {
+    fn default() -> Self {
+        EditorTool::Alien(AlienType::Small)
+    }
+}
+
+impl EditorTool {
+    /// Returns -> a short label for the tool, shown in the editor panel
+    pub fn label(&self, language: Language) -> String {
+        match self {
+            EditorTool::Alien(AlienType::Small) => i18n::text(language, "editor.small_alien"),
+            EditorTool::Alien(AlienType::Medium) => i18n::text(language, "editor.medium_alien"),
+            EditorTool::Alien(AlienType::Large) => i18n::text(language, "editor.large_alien"),
+            EditorTool::Shield(shield_type) => i18n::format(
+                language,
+                "editor.shield",
+                &[("letter", &shield_letter(*shield_type))],
+            ),
+            EditorTool::Erase => i18n::text(language, "editor.erase"),
+        }
+    }
+}
+
+/// Returns -> the letter a shield type is shaped like
+pub fn shield_letter(shield_type: ShieldType) -> char {
+    match shield_type {
+        ShieldType::UppercaseC => 'C',
+        ShieldType::UppercaseO => 'O',
+        ShieldType::UppercaseR => 'R',
+        ShieldType::UppercaseT => 'T',
+        ShieldType::UppercaseW => 'W',
+    }
+}
+
+/// Returns -> the next shield letter, wrapping around after W
+fn next_shield_type(shield_type: ShieldType) -> ShieldType {
+    match shield_type {
+        ShieldType::UppercaseC => ShieldType::UppercaseO,
+        ShieldType::UppercaseO => ShieldType::UppercaseR,
+        ShieldType::UppercaseR => ShieldType::UppercaseT,
+        ShieldType::UppercaseT => ShieldType::UppercaseW,
+        ShieldType::UppercaseW => ShieldType::UppercaseC,
+    }
+}
+
+/// Returns -> the x-coordinate of a shield column
+pub fn shield_x(column: usize) -> f32 {
+    50.0 + column as f32 * 50.0
+}
+
+/// State of the level editor screen
+/// The cursor moves over the alien grid; the row below the grid
+/// (`cursor_row == EDITOR_ROWS`) is where shields are placed.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct LevelEditor {
+    pub level: LevelFile,
+    pub cursor_column: usize,
+    pub cursor_row: usize,
+    pub tool: EditorTool,
+    pub message: Option<String>,
+}
+
+impl LevelEditor {
+    /// Returns -> `true` when the cursor is on the shield row
+    pub fn on_shield_row(&self) -> bool {
+        self.cursor_row == EDITOR_ROWS
+    }
+
+    /// Returns -> the top-left corner of the cell under the cursor
+    pub fn cursor_position(&self) -> Position {
+        if self.on_shield_row() {
+            Position::new(shield_x(self.cursor_column), SHIELD_Y)
+        } else {
+            AlienFormation::slot_position(self.cursor_column, self.cursor_row)
+        }
+    }
+
+    /// Moves the cursor, keeping it on the grid
+    /// # Arguments
+    /// * `dx` - Columns to move right (negative for left)
+    /// * `dy` - Rows to move down (negative for up)
+    pub fn move_cursor(&mut self, dx: i32, dy: i32) {
+        self.cursor_row = (self.cursor_row as i32 + dy).clamp(0, EDITOR_ROWS as i32) as usize;
+
+        let columns = if self.on_shield_row() {
+            SHIELD_COLUMNS
+        } else {
+            FORMATION_COLS
+        };
+        self.cursor_column = (self.cursor_column as i32 + dx).clamp(0, columns as i32 - 1) as usize;
+    }
+
+    /// Selects a tool
+    /// Selecting the shield tool again cycles through the shield letters.
+    /// # Arguments
+    /// * `tool` - The tool to use
+    pub fn select_tool(&mut self, tool: EditorTool) {
+        self.tool = match (self.tool, tool) {
+            (EditorTool::Shield(current), EditorTool::Shield(_)) => {
+                EditorTool::Shield(next_shield_type(current))
+            }
+            _ => tool,
+        };
+    }
+
+    /// Returns -> the alien placed in a grid cell, if any
+    pub fn alien_at(&self, column: usize, row: usize) -> Option<&AlienPlacement> {
+        self.level
+            .layout
+            .aliens
+            .iter()
+            .find(|a| a.column == column && a.row == row)
+    }
+
+    /// Uses the current tool on the cell under the cursor
+    /// # Arguments
+    /// * `language` - The language any message is shown in
+    pub fn apply(&mut self, language: Language) {
+        let (column, row) = (self.cursor_column, self.cursor_row);
+        let on_shield_row = self.on_shield_row();
+        let layout = &mut self.level.layout;
+
+        match (self.tool, on_shield_row) {
+            (EditorTool::Alien(alien_type), false) => {
+                layout.aliens.retain(|a| a.column != column || a.row != row);
+                layout.aliens.push(AlienPlacement {
+                    column,
+                    row,
+                    alien_type,
+                    hit_points: 1,
+                    armor: 0,
+                });
+            }
+            (EditorTool::Shield(shield_type), true) => {
+                let x = shield_x(column);
+                layout.shields.retain(|s| (s.x - x).abs() >= SHIELD_WIDTH);
+                layout.shields.push(ShieldPlacement { x, shield_type });
+                layout.shields.sort_by(|a, b| a.x.total_cmp(&b.x));
+            }
+            (EditorTool::Erase, false) => {
+                layout.aliens.retain(|a| a.column != column || a.row != row);
+            }
+            (EditorTool::Erase, true) => {
+                let x = shield_x(column);
+                layout
+                    .shields
+                    .retain(|s| x < s.x || x >= s.x + SHIELD_WIDTH);
+            }
+            (EditorTool::Alien(_), true) => {
+                self.message = Some(i18n::text(language, "editor.aliens_in_grid"));
+            }
+            (EditorTool::Shield(_), false) => {
+                self.message = Some(i18n::text(language, "editor.shields_on_bottom"));
+            }
+        }
+    }
+
+    /// Changes how fast the formation sweeps
+    /// # Arguments
+    /// * `delta` - Pixels per step to add (negative to slow down)
+    pub fn adjust_speed(&mut self, delta: f32) {
+        let layout = &mut self.level.layout;
+        layout.formation_speed = (layout.formation_speed + delta)
+            .clamp(*FORMATION_SPEED_RANGE.start(), *FORMATION_SPEED_RANGE.end());
+    }
+
+    /// Changes how often the aliens shoot
+    /// # Arguments
+    /// * `delta` - Amount to add to the fire rate multiplier
+    pub fn adjust_fire_rate(&mut self, delta: f64) {
+        let layout = &mut self.level.layout;
+        layout.fire_rate =
+            (layout.fire_rate + delta).clamp(*FIRE_RATE_RANGE.start(), *FIRE_RATE_RANGE.end());
+    }
+
+    /// Saves the level to the custom level file
+    /// # Arguments
+    /// * `language` - The language the result is reported in
+    pub fn save(&mut self, language: Language) {
+        self.message = Some(
+            match storage::save(CUSTOM_LEVEL_FILE, &self.level.to_toml()) {
+                Ok(()) => i18n::format(language, "editor.saved", &[("file", &CUSTOM_LEVEL_FILE)]),
+                Err(error) => i18n::format(language, "editor.save_failed", &[("error", &error)]),
+            },
+        );
+    }
+
+    /// Replaces the level with the one in the custom level file
+    /// # Arguments
+    /// * `language` - The language the result is reported in
+    pub fn load(&mut self, language: Language) {
+        self.message = Some(match load_custom_level(language) {
+            Ok(level) => {
+                self.level = level;
+                i18n::format(language, "editor.loaded", &[("file", &CUSTOM_LEVEL_FILE)])
+            }
+            Err(error) => i18n::format(language, "editor.load_failed", &[("error", &error)]),
+        });
+    }
+}
+
+/// Reads the level saved by the editor
+/// # Arguments
+/// * `language` - The language a problem with the level is described in
+/// # Returns -> The saved level, or a message explaining why it could not be read
+pub fn load_custom_level(language: Language) -> Result<LevelFile, String> {
+    let source = storage::load(CUSTOM_LEVEL_FILE).map_err(|error| error.to_string())?;
+    LevelFile::parse(&source).map_err(|error| error.message(language))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test that the cursor stays on the grid and on the shield row
+    #[test]
+    fn test_move_cursor() {
+        let mut editor = LevelEditor::default();
+
+        editor.move_cursor(-1, -1);
+        assert_eq!((editor.cursor_column, editor.cursor_row), (0, 0));
+
+        editor.move_cursor(100, 0);
+        assert_eq!(editor.cursor_column, FORMATION_COLS - 1);
+
+        editor.move_cursor(0, 100);
+        assert!(editor.on_shield_row());
+        editor.move_cursor(100, 0);
+        assert_eq!(editor.cursor_column, SHIELD_COLUMNS - 1);
+
+        editor.move_cursor(0, -1);
+        assert_eq!(editor.cursor_column, FORMATION_COLS - 1);
+    }
+
+    // Test that aliens can be placed, replaced and erased
+    #[test]
+    fn test_place_and_erase_aliens() {
+        let mut editor = LevelEditor::default();
+        editor.level.layout.aliens.clear();
+
+        editor.move_cursor(2, 5);
+        editor.select_tool(EditorTool::Alien(AlienType::Medium));
+        editor.apply(Language::English);
+        assert_eq!(
+            editor.alien_at(2, 5).map(|a| a.alien_type),
+            Some(AlienType::Medium)
+        );
+
+        editor.select_tool(EditorTool::Alien(AlienType::Large));
+        editor.apply(Language::English);
+        assert_eq!(editor.level.layout.aliens.len(), 1);
+        assert_eq!(
+            editor.alien_at(2, 5).map(|a| a.alien_type),
+            Some(AlienType::Large)
+        );
+
+        editor.select_tool(EditorTool::Erase);
+        editor.apply(Language::English);
+        assert!(editor.alien_at(2, 5).is_none());
+    }
+
+    // Test that shields replace any shield they overlap and can be erased
+    #[test]
+    fn test_place_and_erase_shields() {
+        let mut editor = LevelEditor::default();
+        editor.level.layout.shields.clear();
+        editor.move_cursor(0, EDITOR_ROWS as i32);
+
+        editor.select_tool(EditorTool::Shield(ShieldType::UppercaseC));
+        editor.apply(Language::English);
+        editor.move_cursor(1, 0);
+        editor.select_tool(EditorTool::Shield(ShieldType::UppercaseC));
+        editor.apply(Language::English);
+
+        let shields = &editor.level.layout.shields;
+        assert_eq!(shields.len(), 1);
+        assert_eq!(shields[0].x, shield_x(1));
+        assert_eq!(shields[0].shield_type, ShieldType::UppercaseO);
+
+        editor.move_cursor(1, 0);
+        editor.select_tool(EditorTool::Erase);
+        editor.apply(Language::English);
+        assert!(editor.level.layout.shields.is_empty());
+    }
+
+    // Test that tools only work on the matching part of the grid
+    #[test]
+    fn test_tool_on_wrong_row() {
+        let mut editor = LevelEditor::default();
+        let level = editor.level.clone();
+
+        editor.select_tool(EditorTool::Shield(ShieldType::UppercaseT));
+        editor.apply(Language::English);
+
+        assert_eq!(editor.level, level);
+        assert!(editor.message.is_some());
+    }
+
+    // Test that speed and fire rate stay within their limits
+    #[test]
+    fn test_adjust_settings() {
+        let mut editor = LevelEditor::default();
+
+        editor.adjust_speed(5.0);
+        assert_eq!(editor.level.layout.formation_speed, 25.0);
+        editor.adjust_speed(-1000.0);
+        assert_eq!(editor.level.layout.formation_speed, 5.0);
+
+        editor.adjust_fire_rate(0.25);
+        assert_eq!(editor.level.layout.fire_rate, 1.25);
+        editor.adjust_fire_rate(100.0);
+        assert_eq!(editor.level.layout.fire_rate, 4.0);
+    }
+}
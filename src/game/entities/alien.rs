@@ -1,10 +1,18 @@
+use crate::game::entities::dive::Dive;
+use crate::game::entities::{next_entity_id, EntityId};
+use crate::game::formation_script::ScriptRunner;
 use crate::utils::position::Position;
+use serde::{Deserialize, Serialize};
 
 pub const GAME_WIDTH: f32 = 1024.0;
+pub const FORMATION_START_Y: f32 = 50.0;
+pub const FORMATION_COLS: usize = 11;
+pub const ALIEN_HIT_FLASH_DURATION: f64 = 0.15;
 
 /// Represents the different types of aliens in the game
 /// Each alien type has different point values and sizes.
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum AlienType {
     Small,
     Medium,
@@ -31,15 +39,39 @@ impl AlienType {
     }
 }
 
-/// Aliens have a position, type, size, and animation state.
+/// How much punishment an alien can take before it is destroyed
+/// Armor absorbs hits first; once it is gone each hit removes a hit point.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct AlienToughness {
+    pub hit_points: i32,
+    pub armor: i32,
+}
+
+impl Default for AlienToughness {
+    fn default() -> Self {
+        Self {
+            hit_points: 1,
+            armor: 0,
+        }
+    }
+}
+
+/// Aliens have a position, type, size, animation state and toughness.
 #[derive(Clone, PartialEq)]
 pub struct Alien {
+    pub id: EntityId,
     pub position: Position,
     pub alien_type: AlienType,
     pub width: f32,
     pub height: f32,
     pub is_alive: bool,
     pub animation_frame: usize,
+    pub hit_points: i32,
+    pub max_hit_points: i32,
+    pub armor: i32,
+    pub max_armor: i32,
+    pub hit_flash_timer: f64,
+    pub dive: Option<Dive>,
 }
 
 /// Creates a new alien at the specified position and type
@@ -52,14 +84,82 @@ impl Alien {
     pub fn new(x: f32, y: f32, alien_type: AlienType) -> Self {
         let (width, height) = alien_type.size();
         Self {
+            id: next_entity_id(),
             position: Position::new(x, y),
             alien_type,
             width,
             height,
             is_alive: true,
             animation_frame: 0,
+            hit_points: 1,
+            max_hit_points: 1,
+            armor: 0,
+            max_armor: 0,
+            hit_flash_timer: 0.0,
+            dive: None,
+        }
+    }
+
+    /// Returns -> `true` while the alien has broken formation to dive
+    pub fn is_diving(&self) -> bool {
+        self.dive.is_some()
+    }
+
+    /// Returns -> the alien's place in the formation, which differs from its
+    /// position while it is diving
+    pub fn formation_position(&self) -> &Position {
+        match &self.dive {
+            Some(dive) => &dive.slot,
+            None => &self.position,
+        }
+    }
+
+    /// Returns -> a mutable reference to the alien's place in the formation
+    pub fn formation_position_mut(&mut self) -> &mut Position {
+        match &mut self.dive {
+            Some(dive) => &mut dive.slot,
+            None => &mut self.position,
         }
     }
+
+    /// Gives the alien extra hit points and armor
+    /// # Arguments
+    /// * `toughness` - The hit points and armor the alien starts with
+    pub fn set_toughness(&mut self, toughness: AlienToughness) {
+        self.hit_points = toughness.hit_points.max(1);
+        self.max_hit_points = self.hit_points;
+        self.armor = toughness.armor.max(0);
+        self.max_armor = self.armor;
+    }
+
+    /// Applies one hit to the alien
+    /// Armor is stripped before hit points are lost. The caller is
+    /// responsible for destroying the alien once this returns `true`.
+    /// # Returns -> `true` if the hit brought the alien's hit points to zero
+    pub fn take_hit(&mut self) -> bool {
+        if !self.is_alive {
+            return false;
+        }
+
+        self.hit_flash_timer = ALIEN_HIT_FLASH_DURATION;
+        if self.armor > 0 {
+            self.armor -= 1;
+        } else {
+            self.hit_points -= 1;
+        }
+        self.hit_points <= 0
+    }
+
+    /// Returns -> `true` if the alien has taken a hit but is still alive
+    pub fn is_damaged(&self) -> bool {
+        self.hit_points < self.max_hit_points || self.armor < self.max_armor
+    }
+
+    /// Returns -> the points for destroying this alien
+    /// Tougher aliens are worth their type's points once per hit they can take.
+    pub fn points(&self) -> i32 {
+        self.alien_type.points() * (self.max_hit_points + self.max_armor)
+    }
 }
 
 // Represents a formation of aliens that move together
@@ -76,6 +176,10 @@ pub struct AlienFormation {
     pub move_interval: f64,
     pub should_descend: bool,
     pub aliens_killed: usize,
+    /// Divers that flew off the bottom of the screen instead of returning
+    pub aliens_escaped: usize,
+    pub dive_timer: f64,
+    pub script: Option<ScriptRunner>,
 }
 
 /// Creates a new alien formation with a standard grid layout
@@ -87,13 +191,6 @@ impl AlienFormation {
     pub fn new(_screen_width: f32) -> Self {
         let mut aliens = Vec::new();
         let rows = 5;
-        let cols = 11;
-        let spacing_x = 50.0;
-        let spacing_y = 50.0;
-
-        let formation_width = cols as f32 * spacing_x;
-        let start_x = (GAME_WIDTH - formation_width) / 2.0;
-        let start_y = 50.0;
 
         for row in 0..rows {
             let alien_type = match row {
@@ -102,10 +199,9 @@ impl AlienFormation {
                 _ => AlienType::Large,
             };
 
-            for col in 0..cols {
-                let x = start_x + col as f32 * spacing_x;
-                let y = start_y + row as f32 * spacing_y;
-                aliens.push(Alien::new(x, y, alien_type));
+            for col in 0..FORMATION_COLS {
+                let position = Self::slot_position(col, row);
+                aliens.push(Alien::new(position.x, position.y, alien_type));
             }
         }
 
@@ -117,12 +213,31 @@ impl AlienFormation {
             move_interval: 0.5,
             should_descend: false,
             aliens_killed: 0,
+            aliens_escaped: 0,
+            dive_timer: 0.0,
+            script: None,
         }
     }
 
+    /// Gets the starting position of a slot in the formation grid
+    /// # Arguments
+    /// * `column` - The column, counted from the left
+    /// * `row` - The row, counted from the top
+    /// # Returns -> The top-left corner of the slot
+    pub fn slot_position(column: usize, row: usize) -> Position {
+        let spacing_x = 50.0;
+        let spacing_y = 50.0;
+        let start_x = (GAME_WIDTH - FORMATION_COLS as f32 * spacing_x) / 2.0;
+
+        Position::new(
+            start_x + column as f32 * spacing_x,
+            FORMATION_START_Y + row as f32 * spacing_y,
+        )
+    }
+
     /// Checks if any living alien has reached the edge of the screen
     /// This is used to determine when the formation should change direction
-    /// and move downward
+    /// and move downward. Diving aliens count from their formation slot.
     /// # Returns -> `true` if any alien has reached the edge, `false` otherwise
 
     pub fn check_edges(&self) -> bool {
@@ -131,8 +246,9 @@ impl AlienFormation {
                 continue;
             }
 
-            if (self.direction > 0.0 && alien.position.x + alien.width >= GAME_WIDTH - 20.0)
-                || (self.direction < 0.0 && alien.position.x <= 20.0)
+            let position = alien.formation_position();
+            if (self.direction > 0.0 && position.x + alien.width >= GAME_WIDTH - 20.0)
+                || (self.direction < 0.0 && position.x <= 20.0)
             {
                 return true;
             }
@@ -147,8 +263,41 @@ impl AlienFormation {
         self.aliens.iter().filter(|a| a.is_alive).count()
     }
 
+    /// Applies per-row toughness to the formation
+    /// # Arguments
+    /// * `rows` - The toughness for each row, top row first. Rows without an
+    ///   entry keep the standard single hit point.
+    pub fn apply_toughness(&mut self, rows: &[AlienToughness]) {
+        for (index, alien) in self.aliens.iter_mut().enumerate() {
+            if let Some(toughness) = rows.get(index / FORMATION_COLS) {
+                alien.set_toughness(*toughness);
+            }
+        }
+    }
+
+    /// Moves the formation back up so its highest living alien is at the starting height
+    /// This is used when aliens reach the invasion line on difficulties where
+    /// that only costs the player a life.
+    pub fn retreat(&mut self) {
+        let highest_y = self
+            .aliens
+            .iter()
+            .filter(|a| a.is_alive)
+            .map(|a| a.formation_position().y)
+            .min_by(|a, b| a.partial_cmp(b).unwrap());
+
+        if let Some(highest_y) = highest_y {
+            let offset = highest_y - FORMATION_START_Y;
+            for alien in &mut self.aliens {
+                alien.formation_position_mut().y -= offset;
+            }
+        }
+    }
+
     /// Gets the y-coordinate of the lowest living alien in the formation
     /// This is used to check if aliens have reached the bottom of the screen.
+    /// Diving aliens are measured from their formation slot, so a dive never
+    /// counts as an invasion.
     /// # Returns -> The y-coordinate of the bottom edge of the lowest alien, or 0.0 if no
     /// aliens are alive
 
@@ -156,7 +305,7 @@ impl AlienFormation {
         self.aliens
             .iter()
             .filter(|a| a.is_alive)
-            .map(|a| a.position.y + a.height)
+            .map(|a| a.formation_position().y + a.height)
             .max_by(|a, b| a.partial_cmp(b).unwrap())
             .unwrap_or(0.0)
     }
@@ -195,6 +344,67 @@ mod tests {
         assert_eq!(alien.animation_frame, 0);
     }
 
+    // Test that every alien gets its own ID, which stays with it as the formation moves
+    #[test]
+    fn test_alien_ids() {
+        let mut formation = AlienFormation::new(GAME_WIDTH);
+        let ids: Vec<_> = formation.aliens.iter().map(|a| a.id).collect();
+
+        let mut unique = ids.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(unique.len(), ids.len());
+
+        formation.aliens[0].take_hit();
+        for alien in &mut formation.aliens {
+            alien.position.x += 10.0;
+        }
+        assert_eq!(
+            formation.aliens.iter().map(|a| a.id).collect::<Vec<_>>(),
+            ids
+        );
+        assert_ne!(Alien::new(0.0, 0.0, AlienType::Small).id, ids[0]);
+    }
+
+    // Test that armor is stripped before hit points and the last hit kills
+    #[test]
+    fn test_alien_take_hit() {
+        let mut alien = Alien::new(100.0, 200.0, AlienType::Small);
+        alien.set_toughness(AlienToughness {
+            hit_points: 2,
+            armor: 1,
+        });
+        assert_eq!(alien.points(), 90);
+        assert!(!alien.is_damaged());
+
+        assert!(!alien.take_hit());
+        assert_eq!(alien.armor, 0);
+        assert_eq!(alien.hit_points, 2);
+        assert!(alien.is_damaged());
+        assert!(alien.hit_flash_timer > 0.0);
+
+        assert!(!alien.take_hit());
+        assert_eq!(alien.hit_points, 1);
+        assert!(alien.take_hit());
+
+        alien.is_alive = false;
+        assert!(!alien.take_hit());
+    }
+
+    // Test that toughness is applied per row
+    #[test]
+    fn test_apply_toughness() {
+        let mut formation = AlienFormation::new(1024.0);
+        formation.apply_toughness(&[AlienToughness {
+            hit_points: 3,
+            armor: 0,
+        }]);
+
+        assert_eq!(formation.aliens[0].hit_points, 3);
+        assert_eq!(formation.aliens[10].hit_points, 3);
+        assert_eq!(formation.aliens[11].hit_points, 1);
+    }
+
     // Test that a new formation is created with the correct initial state
     #[test]
     fn test_alien_formation_creation() {
@@ -253,6 +463,44 @@ mod tests {
         assert_eq!(formation.count_living(), 0);
     }
 
+    // Test that diving aliens are measured from their slot, not their dive position
+    #[test]
+    fn test_diving_alien_uses_slot() {
+        use crate::game::entities::dive::DiveOutcome;
+
+        let mut formation = AlienFormation::new(1024.0);
+        let lowest = formation.get_lowest_y();
+
+        let alien = &mut formation.aliens[54];
+        alien.dive = Some(Dive::new(
+            alien.position.clone(),
+            500.0,
+            650.0,
+            DiveOutcome::Return,
+        ));
+        alien.position = Position::new(GAME_WIDTH - 10.0, 640.0);
+
+        assert!(formation.aliens[54].is_diving());
+        assert_eq!(formation.get_lowest_y(), lowest);
+        assert!(!formation.check_edges());
+    }
+
+    // Test that retreat moves the formation back to its starting height
+    #[test]
+    fn test_retreat() {
+        let mut formation = AlienFormation::new(1024.0);
+        formation.aliens[0].is_alive = false;
+        let initial_lowest = formation.get_lowest_y();
+
+        for alien in &mut formation.aliens {
+            alien.position.y += 300.0;
+        }
+        formation.retreat();
+
+        assert_eq!(formation.get_lowest_y(), initial_lowest);
+        assert_eq!(formation.aliens[1].position.y, FORMATION_START_Y);
+    }
+
     // Test that the formation correctly finds the lowest living alien
     #[test]
     fn test_get_lowest_y() {
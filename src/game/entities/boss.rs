@@ -0,0 +1,354 @@
+use crate::utils::position::Position;
+
+/// Points awarded for destroying the boss
+pub const BOSS_DEFEAT_POINTS: i32 = 1000;
+
+/// One destructible part of the boss
+/// Segments are positioned relative to the boss's top-left corner. Armor
+/// segments soak up shots; the boss is only defeated once every weak point
+/// has been destroyed.
+#[derive(Clone, PartialEq, Debug)]
+pub struct BossSegment {
+    pub offset: Position,
+    pub width: f32,
+    pub height: f32,
+    pub hit_points: i32,
+    pub max_hit_points: i32,
+    pub is_weak_point: bool,
+    pub points: i32,
+}
+
+impl BossSegment {
+    /// Creates a new segment at full health
+    /// # Arguments
+    /// * `x` - The x-offset from the boss's left edge
+    /// * `y` - The y-offset from the boss's top edge
+    /// * `width` - The width of the segment in pixels
+    /// * `height` - The height of the segment in pixels
+    /// * `hit_points` - How many hits the segment can take
+    /// * `is_weak_point` - Whether destroying the segment counts towards defeating the boss
+    /// # Returns -> A new BossSegment
+    pub fn new(
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        hit_points: i32,
+        is_weak_point: bool,
+    ) -> Self {
+        Self {
+            offset: Position::new(x, y),
+            width,
+            height,
+            hit_points,
+            max_hit_points: hit_points,
+            is_weak_point,
+            points: if is_weak_point { 300 } else { 50 },
+        }
+    }
+
+    /// Returns -> `true` once the segment has no hit points left
+    pub fn is_destroyed(&self) -> bool {
+        self.hit_points <= 0
+    }
+}
+
+/// The scripted attack phases of the boss, driven by its remaining health
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum BossPhase {
+    /// Slow sweep with single shots from each weak point
+    Sweep,
+    /// Faster sweep firing spreads of three shots
+    Barrage,
+    /// Last stand: fast movement and rapid fire
+    Enraged,
+}
+
+impl BossPhase {
+    /// Returns -> the horizontal speed multiplier for this phase
+    pub fn speed_multiplier(&self) -> f32 {
+        match self {
+            BossPhase::Sweep => 1.0,
+            BossPhase::Barrage => 1.5,
+            BossPhase::Enraged => 2.2,
+        }
+    }
+
+    /// Returns -> the time in seconds between attacks in this phase
+    pub fn attack_interval(&self) -> f64 {
+        match self {
+            BossPhase::Sweep => 1.4,
+            BossPhase::Barrage => 1.6,
+            BossPhase::Enraged => 0.6,
+        }
+    }
+
+    /// Returns -> the horizontal offsets of the shots fired from each weak point
+    pub fn shot_spread(&self) -> &'static [f32] {
+        match self {
+            BossPhase::Sweep | BossPhase::Enraged => &[0.0],
+            BossPhase::Barrage => &[-20.0, 0.0, 20.0],
+        }
+    }
+}
+
+/// A large multi-segment enemy that replaces the alien formation on boss levels
+#[derive(Clone, PartialEq, Debug)]
+pub struct Boss {
+    pub position: Position,
+    pub width: f32,
+    pub height: f32,
+    pub segments: Vec<BossSegment>,
+    pub phase: BossPhase,
+    pub direction: f32,
+    pub speed: f32,
+    pub attack_timer: f64,
+    pub hit_flash_timer: f64,
+}
+
+impl Boss {
+    /// Creates a boss centered at the top of the screen
+    /// The boss is a row of armor plates with three weak points underneath:
+    /// two cannons and a central core.
+    /// # Arguments
+    /// * `screen_width` - The width of the game screen in pixels
+    /// # Returns -> A new Boss at full health
+    pub fn new(screen_width: f32) -> Self {
+        let width = 240.0;
+        let height = 90.0;
+
+        let mut segments = Vec::new();
+        for i in 0..6 {
+            segments.push(BossSegment::new(i as f32 * 40.0, 0.0, 40.0, 30.0, 3, false));
+        }
+        segments.push(BossSegment::new(0.0, 30.0, 60.0, 40.0, 6, true));
+        segments.push(BossSegment::new(60.0, 30.0, 40.0, 30.0, 2, false));
+        segments.push(BossSegment::new(100.0, 30.0, 40.0, 60.0, 10, true));
+        segments.push(BossSegment::new(140.0, 30.0, 40.0, 30.0, 2, false));
+        segments.push(BossSegment::new(180.0, 30.0, 60.0, 40.0, 6, true));
+
+        Self {
+            position: Position::new((screen_width - width) / 2.0, 60.0),
+            width,
+            height,
+            segments,
+            phase: BossPhase::Sweep,
+            direction: 1.0,
+            speed: 80.0,
+            attack_timer: 2.0,
+            hit_flash_timer: 0.0,
+        }
+    }
+
+    /// Returns -> the remaining hit points across all weak points
+    pub fn hit_points(&self) -> i32 {
+        self.segments
+            .iter()
+            .filter(|s| s.is_weak_point)
+            .map(|s| s.hit_points.max(0))
+            .sum()
+    }
+
+    /// Returns -> the total hit points of all weak points at full health
+    pub fn max_hit_points(&self) -> i32 {
+        self.segments
+            .iter()
+            .filter(|s| s.is_weak_point)
+            .map(|s| s.max_hit_points)
+            .sum()
+    }
+
+    /// Returns -> the remaining health as a fraction between 0.0 and 1.0
+    pub fn health_fraction(&self) -> f32 {
+        let max = self.max_hit_points();
+        if max == 0 {
+            0.0
+        } else {
+            self.hit_points() as f32 / max as f32
+        }
+    }
+
+    /// Returns -> `true` once every weak point has been destroyed
+    pub fn is_defeated(&self) -> bool {
+        self.hit_points() == 0
+    }
+
+    /// Works out which phase the boss should be in for its remaining health
+    /// # Returns -> The phase matching the current health
+    pub fn phase_for_health(&self) -> BossPhase {
+        let health = self.health_fraction();
+        if health > 0.66 {
+            BossPhase::Sweep
+        } else if health > 0.33 {
+            BossPhase::Barrage
+        } else {
+            BossPhase::Enraged
+        }
+    }
+
+    /// Moves the boss side to side, bouncing off the screen edges
+    /// # Arguments
+    /// * `dt` - Delta time in seconds since the last update
+    /// * `screen_width` - The width of the game screen in pixels
+    pub fn update(&mut self, dt: f32, screen_width: f32) {
+        self.position.x += self.direction * self.speed * self.phase.speed_multiplier() * dt;
+
+        if self.position.x <= 20.0 {
+            self.position.x = 20.0;
+            self.direction = 1.0;
+        } else if self.position.x + self.width >= screen_width - 20.0 {
+            self.position.x = screen_width - 20.0 - self.width;
+            self.direction = -1.0;
+        }
+
+        if self.hit_flash_timer > 0.0 {
+            self.hit_flash_timer -= dt as f64;
+        }
+    }
+
+    /// Finds the segment a projectile would hit
+    /// Projectiles travel upward, so when several segments overlap the
+    /// projectile the lowest one is hit first.
+    /// # Arguments
+    /// * `x` - X coordinate of the projectile's top-left corner
+    /// * `y` - Y coordinate of the projectile's top-left corner
+    /// * `width` - Width of the projectile
+    /// * `height` - Height of the projectile
+    /// # Returns -> The index of the segment that was hit, if any
+    pub fn segment_at(&self, x: f32, y: f32, width: f32, height: f32) -> Option<usize> {
+        self.segments
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| !s.is_destroyed())
+            .filter(|(_, s)| {
+                let left = self.position.x + s.offset.x;
+                let top = self.position.y + s.offset.y;
+                x < left + s.width && x + width > left && y < top + s.height && y + height > top
+            })
+            .max_by(|(_, a), (_, b)| {
+                (a.offset.y + a.height)
+                    .partial_cmp(&(b.offset.y + b.height))
+                    .unwrap()
+            })
+            .map(|(index, _)| index)
+    }
+
+    /// Damages a segment by one hit point
+    /// # Arguments
+    /// * `segment_index` - Index of the segment that was hit
+    /// # Returns -> The points for destroying the segment, or 0 if it survived
+    pub fn damage_segment(&mut self, segment_index: usize) -> i32 {
+        let Some(segment) = self.segments.get_mut(segment_index) else {
+            return 0;
+        };
+        if segment.is_destroyed() {
+            return 0;
+        }
+
+        segment.hit_points -= 1;
+        self.hit_flash_timer = 0.1;
+
+        if segment.is_destroyed() {
+            segment.points
+        } else {
+            0
+        }
+    }
+
+    /// Returns -> the screen positions shots are fired from: the bottom center
+    /// of every weak point that is still intact
+    pub fn cannon_positions(&self) -> Vec<(f32, f32)> {
+        self.segments
+            .iter()
+            .filter(|s| s.is_weak_point && !s.is_destroyed())
+            .map(|s| {
+                (
+                    self.position.x + s.offset.x + s.width / 2.0,
+                    self.position.y + s.offset.y + s.height,
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test that a new boss starts at full health in the sweep phase
+    #[test]
+    fn test_boss_creation() {
+        let boss = Boss::new(1024.0);
+        assert_eq!(boss.hit_points(), boss.max_hit_points());
+        assert_eq!(boss.max_hit_points(), 22);
+        assert_eq!(boss.health_fraction(), 1.0);
+        assert_eq!(boss.phase, BossPhase::Sweep);
+        assert_eq!(boss.cannon_positions().len(), 3);
+        assert!(!boss.is_defeated());
+        assert_eq!(boss.position.x + boss.width / 2.0, 512.0);
+    }
+
+    // Test that projectiles hit the lowest segment in their path
+    #[test]
+    fn test_segment_at_hits_lowest_segment() {
+        let boss = Boss::new(1024.0);
+        let core_x = boss.position.x + 115.0;
+        let index = boss.segment_at(core_x, boss.position.y + 50.0, 3.0, 60.0);
+        assert_eq!(index, Some(8));
+        assert!(boss.segments[8].is_weak_point);
+
+        assert_eq!(boss.segment_at(0.0, 500.0, 3.0, 15.0), None);
+    }
+
+    // Test that segments are destroyed after taking all their hit points
+    #[test]
+    fn test_damage_segment() {
+        let mut boss = Boss::new(1024.0);
+        assert_eq!(boss.damage_segment(0), 0);
+        assert_eq!(boss.damage_segment(0), 0);
+        assert_eq!(boss.damage_segment(0), 50);
+        assert!(boss.segments[0].is_destroyed());
+        assert_eq!(boss.damage_segment(0), 0);
+        assert_eq!(
+            boss.segment_at(boss.position.x + 5.0, boss.position.y, 3.0, 5.0),
+            None
+        );
+    }
+
+    // Test that destroying the weak points changes the phase and defeats the boss
+    #[test]
+    fn test_phases_and_defeat() {
+        let mut boss = Boss::new(1024.0);
+
+        for _ in 0..10 {
+            boss.damage_segment(8);
+        }
+        assert_eq!(boss.phase_for_health(), BossPhase::Barrage);
+
+        for _ in 0..6 {
+            boss.damage_segment(6);
+        }
+        assert_eq!(boss.phase_for_health(), BossPhase::Enraged);
+        assert_eq!(boss.cannon_positions().len(), 1);
+
+        for _ in 0..6 {
+            boss.damage_segment(10);
+        }
+        assert!(boss.is_defeated());
+        assert_eq!(boss.health_fraction(), 0.0);
+    }
+
+    // Test that the boss bounces off the screen edges
+    #[test]
+    fn test_boss_movement() {
+        let mut boss = Boss::new(1024.0);
+        boss.position.x = 1024.0 - boss.width - 21.0;
+        boss.update(1.0, 1024.0);
+        assert_eq!(boss.direction, -1.0);
+
+        boss.position.x = 21.0;
+        boss.update(1.0, 1024.0);
+        assert_eq!(boss.direction, 1.0);
+        assert_eq!(boss.position.x, 20.0);
+    }
+}
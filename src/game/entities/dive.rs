@@ -0,0 +1,138 @@
+use crate::utils::position::Position;
+
+/// Seconds a diving alien waits between shots while swooping
+pub const DIVE_SHOT_INTERVAL: f64 = 0.8;
+
+/// A curved flight path for a diving alien
+/// The path is a cubic Bézier curve from the alien's starting point to the
+/// bottom of its swoop.
+#[derive(Clone, PartialEq, Debug)]
+pub struct DivePath {
+    pub points: [Position; 4],
+}
+
+impl DivePath {
+    /// Builds a swooping path that loops out to one side and then curves in
+    /// towards the player
+    /// # Arguments
+    /// * `start` - Where the alien leaves the formation
+    /// * `target_x` - The x-coordinate the dive aims for
+    /// * `bottom_y` - The lowest point of the dive
+    /// # Returns -> A new DivePath
+    pub fn swoop(start: Position, target_x: f32, bottom_y: f32) -> Self {
+        let side = if target_x < start.x { 1.0 } else { -1.0 };
+
+        Self {
+            points: [
+                start.clone(),
+                Position::new(start.x + side * 150.0, start.y - 80.0),
+                Position::new(target_x - side * 200.0, bottom_y - 200.0),
+                Position::new(target_x, bottom_y),
+            ],
+        }
+    }
+
+    /// Evaluates the curve
+    /// # Arguments
+    /// * `t` - How far along the path, from 0.0 (start) to 1.0 (end)
+    /// # Returns -> The position on the path
+    pub fn point_at(&self, t: f32) -> Position {
+        let t = t.clamp(0.0, 1.0);
+        let u = 1.0 - t;
+        let [p0, p1, p2, p3] = &self.points;
+
+        let weights = [u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t];
+        Position::new(
+            weights[0] * p0.x + weights[1] * p1.x + weights[2] * p2.x + weights[3] * p3.x,
+            weights[0] * p0.y + weights[1] * p1.y + weights[2] * p2.y + weights[3] * p3.y,
+        )
+    }
+}
+
+/// What a diving alien does once it reaches the bottom of its swoop
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum DiveOutcome {
+    /// Fly back up and rejoin the formation
+    Return,
+    /// Keep going and leave the bottom of the screen for good
+    Exit,
+}
+
+/// Per-alien movement state while it is away from the formation
+/// The formation keeps moving the alien's `slot` in lockstep, so a returning
+/// alien flies back to wherever its place in the formation has moved to.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Dive {
+    pub path: DivePath,
+    pub progress: f32,
+    pub duration: f32,
+    pub slot: Position,
+    pub outcome: DiveOutcome,
+    pub returning: bool,
+    pub shot_timer: f64,
+}
+
+impl Dive {
+    /// Starts a dive from the alien's place in the formation
+    /// # Arguments
+    /// * `slot` - The alien's current position in the formation
+    /// * `target_x` - The x-coordinate the dive aims for
+    /// * `bottom_y` - The lowest point of the dive
+    /// * `outcome` - Whether the alien returns or exits afterwards
+    /// # Returns -> A new Dive
+    pub fn new(slot: Position, target_x: f32, bottom_y: f32, outcome: DiveOutcome) -> Self {
+        Self {
+            path: DivePath::swoop(slot.clone(), target_x, bottom_y),
+            progress: 0.0,
+            duration: 2.5,
+            slot,
+            outcome,
+            returning: false,
+            shot_timer: DIVE_SHOT_INTERVAL / 2.0,
+        }
+    }
+
+    /// Returns -> `true` while the alien is still on the attacking part of the dive
+    pub fn is_attacking(&self) -> bool {
+        !self.returning && self.progress < 1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test that the path starts and ends at its end points
+    #[test]
+    fn test_path_end_points() {
+        let path = DivePath::swoop(Position::new(100.0, 50.0), 500.0, 650.0);
+
+        assert_eq!(path.point_at(0.0), Position::new(100.0, 50.0));
+        assert_eq!(path.point_at(1.0), Position::new(500.0, 650.0));
+        assert_eq!(path.point_at(2.0), Position::new(500.0, 650.0));
+    }
+
+    // Test that the path curves out to the side away from the target first
+    #[test]
+    fn test_path_loops_out() {
+        let path = DivePath::swoop(Position::new(500.0, 100.0), 200.0, 650.0);
+        let early = path.point_at(0.1);
+
+        assert!(early.x > 500.0);
+        assert!(early.y < 100.0);
+    }
+
+    // Test that a new dive starts attacking from its slot
+    #[test]
+    fn test_new_dive() {
+        let dive = Dive::new(
+            Position::new(100.0, 50.0),
+            500.0,
+            650.0,
+            DiveOutcome::Return,
+        );
+
+        assert_eq!(dive.slot, Position::new(100.0, 50.0));
+        assert!(dive.is_attacking());
+    }
+}
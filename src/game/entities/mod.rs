@@ -1,5 +1,19 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
 pub mod alien;
+pub mod boss;
+pub mod dive;
 pub mod mystery_ship;
 pub mod player;
 pub mod projectile;
 pub mod shield;
+
+/// Identifies one entity for as long as it exists, so the renderer can tell
+/// entities apart when lists change
+pub type EntityId = u64;
+
+/// Returns -> an ID no other entity has been given
+pub fn next_entity_id() -> EntityId {
+    static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
@@ -1,10 +1,41 @@
+use crate::game::entities::{next_entity_id, EntityId};
 use crate::utils::position::Position;
 
+/// Mystery ship points in the order the original arcade machine awarded them
+/// The table is indexed by the number of shots the player has fired, so the
+/// 23rd shot (and every 15th shot after it) is worth 300 points.
+pub const ARCADE_POINTS_TABLE: [i32; 15] = [
+    100, 50, 50, 100, 150, 100, 100, 50, 300, 100, 100, 100, 50, 150, 100,
+];
+
+/// The fewest living aliens that still allow a mystery ship to appear in arcade mode
+pub const ARCADE_MIN_ALIENS_FOR_SPAWN: usize = 8;
+
+/// Decides how many points a mystery ship is worth
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum MysteryShipScoring {
+    /// A random multiple of 50 between 50 and 300, chosen when the ship spawns
+    #[default]
+    Random,
+    /// The arcade rules: points come from the player's shot count, and ships
+    /// only spawn while at least eight aliens remain
+    ShotCount,
+}
+
+/// Looks up the arcade mystery ship score for a shot count
+/// # Arguments
+/// * `shots_fired` - The number of shots the player has fired so far
+/// # Returns -> The points awarded for hitting the mystery ship with that shot
+pub fn arcade_points(shots_fired: u32) -> i32 {
+    ARCADE_POINTS_TABLE[shots_fired as usize % ARCADE_POINTS_TABLE.len()]
+}
+
 /// Represents the special mystery ship that occasionally appears at the top of the screen
 /// The mystery ship moves horizontally across the screen and awards bonus points
 /// when shot by the player. The points awarded are variable.
 #[derive(Clone, PartialEq)]
 pub struct MysteryShip {
+    pub id: EntityId,
     pub position: Position,
     pub width: f32,
     pub height: f32,
@@ -21,6 +52,7 @@ impl MysteryShip {
     /// # Returns -> A new MysteryShip instance
     pub fn new() -> Self {
         Self {
+            id: next_entity_id(),
             position: Position::new(-50.0, 10.0),
             width: 60.0,
             height: 20.0,
@@ -70,6 +102,16 @@ mod tests {
         assert_eq!(ship.points, 100);
     }
 
+    // Test that the 23rd shot and every 15th shot after it score 300 points
+    #[test]
+    fn test_arcade_points() {
+        assert_eq!(arcade_points(23), 300);
+        assert_eq!(arcade_points(38), 300);
+        assert_eq!(arcade_points(53), 300);
+        assert_eq!(arcade_points(1), 50);
+        assert_eq!(arcade_points(22), 50);
+    }
+
     // Test that an inactive ship doesn't move when updated
     #[test]
     fn test_inactive_ship_doesnt_move() {
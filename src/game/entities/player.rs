@@ -1,5 +1,8 @@
 use crate::utils::position::Position;
 
+/// Seconds the ship shows its wreck after being hit
+pub const HIT_ANIMATION_DURATION: f64 = 0.5;
+
 /// Represents the player's ship in the game
 ///
 /// The player can move horizontally and fire projectiles upward.
@@ -12,7 +15,9 @@ pub struct Player {
     pub projectile_spawn_x: f32,
     pub projectile_spawn_y: f32,
     pub speed: f32,
+    /// Whether the ship is drawn flickering (or dimmed) while it is invincible
     pub is_hit: bool,
+    /// Seconds left showing the wreck after a hit
     pub hit_animation_time: f64,
 }
 
@@ -48,6 +53,33 @@ impl Player {
         self.position.x = new_x.min(right_boundary - self.width / 2.0);
     }
 
+    /// Moves the player toward a target x-coordinate, no faster than its speed
+    /// and without leaving the boundaries
+    /// # Arguments
+    /// * `target_x` - Where the centre of the ship should end up
+    /// * `dt` - Delta time in seconds since the last update
+    /// * `left_boundary` - The left boundary of the game area in pixels
+    /// * `right_boundary` - The right boundary of the game area in pixels
+    pub fn move_towards(
+        &mut self,
+        target_x: f32,
+        dt: f32,
+        left_boundary: f32,
+        right_boundary: f32,
+    ) {
+        let step = self.speed * dt;
+        let new_x = self.position.x + (target_x - self.position.x).clamp(-step, step);
+        self.position.x = new_x.clamp(
+            left_boundary + self.width / 2.0,
+            right_boundary - self.width / 2.0,
+        );
+    }
+
+    /// Returns -> `true` while the ship shows its wreck after being hit
+    pub fn is_wrecked(&self) -> bool {
+        self.hit_animation_time > 0.0
+    }
+
     /// Calculates the position where projectiles should spawn
     /// # Returns -> A tuple of (x, y) coordinates for the projectile spawn position
 
@@ -118,6 +150,22 @@ mod tests {
         assert_eq!(player.position.x, boundary - player.width / 2.0);
     }
 
+    // Test that move_towards is limited by speed and boundaries
+    #[test]
+    fn test_move_towards() {
+        let mut player = Player::default();
+        let initial_x = player.position.x;
+
+        player.move_towards(initial_x + 5.0, 0.1, 0.0, 1024.0);
+        assert_eq!(player.position.x, initial_x + 5.0);
+
+        player.move_towards(0.0, 0.1, 0.0, 1024.0);
+        assert_eq!(player.position.x, initial_x + 5.0 - player.speed * 0.1);
+
+        player.move_towards(0.0, 100.0, 0.0, 1024.0);
+        assert_eq!(player.position.x, player.width / 2.0);
+    }
+
     // Test that get_projectile_spawn_position returns the correct position
     #[test]
     fn test_projectile_spawn_position() {
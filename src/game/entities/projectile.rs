@@ -1,11 +1,13 @@
 use crate::game::entities::alien::Alien;
 use crate::game::entities::player::Player;
+use crate::game::entities::{next_entity_id, EntityId};
 use crate::utils::position::Position;
 
 /// Represents a projectile fired by either the player or an alien
 /// Player projectiles move upward, while alien projectiles move downward.
 #[derive(Clone, PartialEq)]
 pub struct Projectile {
+    pub id: EntityId,
     pub position: Position,
     pub velocity: f32,
     pub width: f32,
@@ -26,6 +28,7 @@ impl Projectile {
         let velocity = if is_player_projectile { -400.0 } else { 200.0 };
 
         Self {
+            id: next_entity_id(),
             position: Position::new(x, y),
             velocity,
             width,
@@ -1,18 +1,27 @@
+use crate::game::entities::{next_entity_id, EntityId};
 use crate::utils::position::Position;
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
 
 /// Shield module provides defensive structures shaped like letters that can be damaged by projectiles
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
 pub enum ShieldType {
+    #[serde(rename = "C")]
     UppercaseC,
+    #[serde(rename = "O")]
     UppercaseO,
+    #[serde(rename = "R")]
     UppercaseR,
+    #[serde(rename = "T")]
     UppercaseT,
+    #[serde(rename = "W")]
     UppercaseW,
 }
 
 /// A defensive structure composed of destructible segments arranged in a letter pattern
 #[derive(Clone, PartialEq)]
 pub struct Shield {
+    pub id: EntityId,
     pub position: Position,
     pub width: f32,
     pub height: f32,
@@ -33,6 +42,7 @@ impl Shield {
         let segments = create_shield_segments(shield_type);
 
         Self {
+            id: next_entity_id(),
             position: Position::new(x, y),
             width,
             height,
@@ -96,6 +106,61 @@ impl Shield {
 
         false
     }
+
+    /// Restores every segment of the shield to its original letter pattern
+    pub fn regenerate(&mut self) {
+        self.segments = create_shield_segments(self.shield_type);
+    }
+
+    /// Counts the segments of the letter pattern that have been shot away
+    /// # Returns -> The number of destroyed segments
+    pub fn destroyed_segment_count(&self) -> usize {
+        create_shield_segments(self.shield_type)
+            .iter()
+            .flatten()
+            .zip(self.segments.iter().flatten())
+            .filter(|(&original, &current)| original && !current)
+            .count()
+    }
+
+    /// Rebuilds a random selection of the destroyed segments
+    /// # Arguments
+    /// * `percent` - Percentage (0-100) of the destroyed segments to restore
+    /// # Returns -> The number of segments that were restored
+    pub fn repair(&mut self, percent: u32) -> usize {
+        let original = create_shield_segments(self.shield_type);
+        let mut destroyed: Vec<(usize, usize)> = Vec::new();
+
+        for (y, row) in original.iter().enumerate() {
+            for (x, &active) in row.iter().enumerate() {
+                if active && !self.segments[y][x] {
+                    destroyed.push((y, x));
+                }
+            }
+        }
+
+        let to_repair = destroyed.len() * percent.min(100) as usize / 100;
+        destroyed.shuffle(&mut rand::thread_rng());
+
+        for &(y, x) in destroyed.iter().take(to_repair) {
+            self.segments[y][x] = true;
+        }
+
+        to_repair
+    }
+}
+
+/// Creates the row of letter shields that protects the player at the start of a game
+/// # Returns -> A vector of six shields spelling out "CORTWO"
+pub fn default_shields() -> Vec<Shield> {
+    vec![
+        Shield::new(100.0, 500.0, ShieldType::UppercaseC),
+        Shield::new(250.0, 500.0, ShieldType::UppercaseO),
+        Shield::new(400.0, 500.0, ShieldType::UppercaseR),
+        Shield::new(550.0, 500.0, ShieldType::UppercaseT),
+        Shield::new(700.0, 500.0, ShieldType::UppercaseW),
+        Shield::new(850.0, 500.0, ShieldType::UppercaseO),
+    ]
 }
 
 pub fn create_shield_segments(shield_type: ShieldType) -> Vec<Vec<bool>> {
@@ -297,4 +362,50 @@ mod tests {
         assert!(!c_segments[2][12]);
         assert!(o_segments[2][12]);
     }
+
+    // Test that regenerate restores a damaged shield to its original pattern
+    #[test]
+    fn test_regenerate() {
+        let mut shield = Shield::new(0.0, 0.0, ShieldType::UppercaseT);
+        shield.check_projectile_collision(10.0, 0.0, 20.0, 20.0);
+        assert!(shield.destroyed_segment_count() > 0);
+
+        shield.regenerate();
+
+        assert_eq!(shield.destroyed_segment_count(), 0);
+        assert_eq!(
+            shield.segments,
+            create_shield_segments(ShieldType::UppercaseT)
+        );
+    }
+
+    // Test that repair restores the requested share of destroyed segments
+    #[test]
+    fn test_repair() {
+        let mut shield = Shield::new(0.0, 0.0, ShieldType::UppercaseO);
+        for row in shield.segments.iter_mut() {
+            for segment in row.iter_mut() {
+                *segment = false;
+            }
+        }
+        let destroyed = shield.destroyed_segment_count();
+
+        let repaired = shield.repair(50);
+
+        assert_eq!(repaired, destroyed / 2);
+        assert_eq!(shield.destroyed_segment_count(), destroyed - repaired);
+        assert_eq!(shield.repair(100), destroyed - repaired);
+        assert_eq!(shield.destroyed_segment_count(), 0);
+    }
+
+    // Test that repair never switches on segments outside the letter pattern
+    #[test]
+    fn test_repair_keeps_letter_shape() {
+        let mut shield = Shield::new(0.0, 0.0, ShieldType::UppercaseC);
+        shield.repair(100);
+        assert_eq!(
+            shield.segments,
+            create_shield_segments(ShieldType::UppercaseC)
+        );
+    }
 }
@@ -0,0 +1,17 @@
+use crate::game::entities::boss::BossPhase;
+
+/// Notable things that happened during a game update
+/// Logic functions push events onto `GameState::events` so that the UI (and any
+/// other interested system) can react without the logic knowing about them.
+/// The queue is cleared at the start of every frame.
+#[derive(Clone, PartialEq, Debug)]
+pub enum GameEvent {
+    /// The player was awarded an extra life and now has `lives` lives
+    ExtraLife { lives: i32 },
+    /// The boss moved into a new attack phase
+    BossPhaseChanged { phase: BossPhase },
+    /// The boss's last weak point was destroyed
+    BossDefeated,
+    /// Every alien (or the boss) of `level` was destroyed
+    LevelCleared { level: i32 },
+}
@@ -0,0 +1,511 @@
+use std::f32::consts::TAU;
+use std::fmt;
+
+/// The most a script can speed the formation up (or slow it down) in total,
+/// however many times its `accelerate` steps loop
+pub const MAX_ACCELERATION: f32 = 2.0;
+
+/// One instruction in a formation movement script
+/// Durations are in seconds and distances in pixels.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum MovementStep {
+    /// The classic side-to-side sweep that drops down at the screen edges.
+    /// Without a duration the sweep carries on for the rest of the level.
+    Sweep { duration: Option<f64> },
+    /// A ripple that rolls across the columns, moving each one up and down
+    SineWave {
+        amplitude: f32,
+        period: f32,
+        duration: f64,
+    },
+    /// Moves the whole formation around a circle and back to where it started
+    Circle {
+        radius: f32,
+        period: f32,
+        duration: f64,
+    },
+    /// Pulls the left and right halves of the formation apart
+    Split { distance: f32, duration: f64 },
+    /// Closes a previous split back together
+    Merge { duration: f64 },
+    /// Holds the formation still
+    Pause { duration: f64 },
+    /// Multiplies the sweep speed and tempo; takes effect immediately
+    Accelerate { factor: f32 },
+}
+
+impl MovementStep {
+    /// Returns -> how long the step runs, or `None` if it never finishes
+    pub fn duration(&self) -> Option<f64> {
+        match *self {
+            MovementStep::Sweep { duration } => duration,
+            MovementStep::SineWave { duration, .. }
+            | MovementStep::Circle { duration, .. }
+            | MovementStep::Split { duration, .. }
+            | MovementStep::Merge { duration }
+            | MovementStep::Pause { duration } => Some(duration),
+            MovementStep::Accelerate { .. } => Some(0.0),
+        }
+    }
+}
+
+impl fmt::Display for MovementStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MovementStep::Sweep { duration: None } => write!(f, "sweep"),
+            MovementStep::Sweep {
+                duration: Some(duration),
+            } => write!(f, "sweep {duration}"),
+            MovementStep::SineWave {
+                amplitude,
+                period,
+                duration,
+            } => write!(f, "sine {amplitude} {period} {duration}"),
+            MovementStep::Circle {
+                radius,
+                period,
+                duration,
+            } => write!(f, "circle {radius} {period} {duration}"),
+            MovementStep::Split { distance, duration } => write!(f, "split {distance} {duration}"),
+            MovementStep::Merge { duration } => write!(f, "merge {duration}"),
+            MovementStep::Pause { duration } => write!(f, "pause {duration}"),
+            MovementStep::Accelerate { factor } => write!(f, "accelerate {factor}"),
+        }
+    }
+}
+
+/// A problem found while parsing a movement script
+#[derive(Clone, PartialEq, Debug)]
+pub struct ScriptError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// A formation movement script
+/// Scripts are plain text with one command per line (or separated by `;`).
+/// `#` starts a comment. The script loops once it reaches the end.
+///
+/// ```text
+/// sweep 6                 # classic sweep for 6 seconds
+/// sine 20 2 4             # ripple: amplitude, period, duration
+/// circle 40 3 3           # radius, period, duration
+/// split 120 1.5           # distance, duration
+/// pause 1
+/// merge 1.5
+/// accelerate 1.2
+/// ```
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct FormationScript {
+    pub steps: Vec<MovementStep>,
+}
+
+impl FormationScript {
+    /// Parses a script from its text form
+    /// # Arguments
+    /// * `source` - The script text
+    /// # Returns -> The parsed script, or the first error found
+    pub fn parse(source: &str) -> Result<Self, ScriptError> {
+        let mut steps = Vec::new();
+
+        for (index, line) in source.lines().enumerate() {
+            let line_number = index + 1;
+            let code = line.split('#').next().unwrap_or_default();
+
+            for command in code.split(';') {
+                let words: Vec<&str> = command.split_whitespace().collect();
+                if let Some((name, args)) = words.split_first() {
+                    steps.push(parse_step(name, args, line_number)?);
+                }
+            }
+        }
+
+        if steps
+            .iter()
+            .all(|step| matches!(step, MovementStep::Accelerate { .. }))
+        {
+            return Err(ScriptError {
+                line: 0,
+                message: "script needs at least one timed movement command".to_string(),
+            });
+        }
+
+        Ok(Self { steps })
+    }
+}
+
+impl fmt::Display for FormationScript {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for step in &self.steps {
+            writeln!(f, "{step}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses a single command and its arguments
+fn parse_step(name: &str, args: &[&str], line: usize) -> Result<MovementStep, ScriptError> {
+    let error = |message: String| ScriptError { line, message };
+
+    let expected = match name {
+        "sweep" => 0..=1,
+        "sine" | "circle" => 3..=3,
+        "split" => 2..=2,
+        "merge" | "pause" | "accelerate" => 1..=1,
+        _ => return Err(error(format!("unknown command `{name}`"))),
+    };
+    if !expected.contains(&args.len()) {
+        return Err(error(format!(
+            "`{name}` expects {} argument(s), got {}",
+            expected.end(),
+            args.len()
+        )));
+    }
+
+    let mut numbers = Vec::new();
+    for arg in args {
+        match arg.parse::<f32>() {
+            Ok(value) if value.is_finite() && value >= 0.0 => numbers.push(value),
+            _ => return Err(error(format!("`{arg}` is not a valid number"))),
+        }
+    }
+
+    let step = match name {
+        "sweep" => MovementStep::Sweep {
+            duration: numbers.first().map(|&d| d as f64),
+        },
+        "sine" => MovementStep::SineWave {
+            amplitude: numbers[0],
+            period: numbers[1],
+            duration: numbers[2] as f64,
+        },
+        "circle" => MovementStep::Circle {
+            radius: numbers[0],
+            period: numbers[1],
+            duration: numbers[2] as f64,
+        },
+        "split" => MovementStep::Split {
+            distance: numbers[0],
+            duration: numbers[1] as f64,
+        },
+        "merge" => MovementStep::Merge {
+            duration: numbers[0] as f64,
+        },
+        "pause" => MovementStep::Pause {
+            duration: numbers[0] as f64,
+        },
+        _ => MovementStep::Accelerate { factor: numbers[0] },
+    };
+
+    if matches!(
+        step,
+        MovementStep::SineWave { period, .. } | MovementStep::Circle { period, .. } if period == 0.0
+    ) {
+        return Err(error("period must be greater than zero".to_string()));
+    }
+
+    Ok(step)
+}
+
+/// What the formation should do this frame
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ScriptTick {
+    /// Whether the classic sweep movement is active
+    pub sweeping: bool,
+    /// Factor to multiply the formation's speed by (1.0 for no change)
+    pub speed_factor: f32,
+}
+
+/// Runs a FormationScript against a formation
+/// The runner tracks the current step and how far each formation column has
+/// been displaced by the script, so the formation update can move aliens by
+/// the change in displacement each frame.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ScriptRunner {
+    pub script: FormationScript,
+    pub step: usize,
+    pub elapsed: f64,
+    pub columns: usize,
+    base: Vec<(f32, f32)>,
+    split: f32,
+    /// Total speed factor applied by the script so far
+    acceleration: f32,
+}
+
+impl ScriptRunner {
+    /// Creates a runner at the start of a script
+    /// # Arguments
+    /// * `script` - The script to run
+    /// * `columns` - The number of columns in the formation
+    /// # Returns -> A new ScriptRunner
+    pub fn new(script: FormationScript, columns: usize) -> Self {
+        Self {
+            script,
+            step: 0,
+            elapsed: 0.0,
+            columns,
+            base: vec![(0.0, 0.0); columns],
+            split: 0.0,
+            acceleration: 1.0,
+        }
+    }
+
+    /// Returns -> the step currently running, if the script has any
+    pub fn current_step(&self) -> Option<MovementStep> {
+        self.script.steps.get(self.step).copied()
+    }
+
+    /// Advances the script by one frame
+    /// # Arguments
+    /// * `dt` - Time elapsed since the last update in seconds
+    /// # Returns -> What the formation should do this frame
+    pub fn advance(&mut self, dt: f64) -> ScriptTick {
+        let mut tick = ScriptTick {
+            sweeping: false,
+            speed_factor: 1.0,
+        };
+        let mut remaining = dt;
+
+        // Instant steps (accelerate) take no time, so cap the number of steps
+        // a single frame may finish to avoid looping forever.
+        for _ in 0..=self.script.steps.len() {
+            let Some(step) = self.current_step() else {
+                break;
+            };
+
+            if let MovementStep::Accelerate { factor } = step {
+                let total =
+                    (self.acceleration * factor).clamp(1.0 / MAX_ACCELERATION, MAX_ACCELERATION);
+                tick.speed_factor *= total / self.acceleration;
+                self.acceleration = total;
+                self.finish_step(step);
+                continue;
+            }
+
+            let Some(duration) = step.duration() else {
+                tick.sweeping = matches!(step, MovementStep::Sweep { .. });
+                self.elapsed += remaining;
+                break;
+            };
+
+            let left = duration - self.elapsed;
+            if remaining < left {
+                self.elapsed += remaining;
+                tick.sweeping = matches!(step, MovementStep::Sweep { .. });
+                break;
+            }
+
+            remaining -= left.max(0.0);
+            self.elapsed = duration;
+            self.finish_step(step);
+        }
+
+        tick
+    }
+
+    /// Gets how far a column has been moved by the script so far
+    /// # Arguments
+    /// * `column` - The column index
+    /// # Returns -> The (x, y) displacement in pixels
+    pub fn displacement(&self, column: usize) -> (f32, f32) {
+        let (base_x, base_y) = self.base.get(column).copied().unwrap_or_default();
+        let (step_x, step_y) = self
+            .current_step()
+            .map_or((0.0, 0.0), |step| self.step_offset(step, column));
+        (base_x + step_x, base_y + step_y)
+    }
+
+    /// Works out the offset a step has produced for a column so far
+    fn step_offset(&self, step: MovementStep, column: usize) -> (f32, f32) {
+        let t = self.elapsed as f32;
+        let progress = |duration: f64| {
+            if duration <= 0.0 {
+                1.0
+            } else {
+                (self.elapsed / duration).clamp(0.0, 1.0) as f32
+            }
+        };
+        let side = if (column as f32) < self.columns as f32 / 2.0 {
+            -1.0
+        } else {
+            1.0
+        };
+
+        match step {
+            MovementStep::SineWave {
+                amplitude, period, ..
+            } => {
+                let phase = column as f32 * 0.6;
+                let y = amplitude * ((TAU * t / period + phase).sin() - phase.sin());
+                (0.0, y)
+            }
+            MovementStep::Circle { radius, period, .. } => {
+                let angle = TAU * t / period;
+                (radius * angle.sin(), radius * (1.0 - angle.cos()))
+            }
+            MovementStep::Split { distance, duration } => {
+                (side * distance / 2.0 * progress(duration), 0.0)
+            }
+            MovementStep::Merge { duration } => (-side * self.split * progress(duration), 0.0),
+            _ => (0.0, 0.0),
+        }
+    }
+
+    /// Bakes a finished step's movement into the base displacement and moves
+    /// on to the next step, looping at the end of the script
+    fn finish_step(&mut self, step: MovementStep) {
+        for column in 0..self.columns {
+            let (x, y) = self.step_offset(step, column);
+            self.base[column].0 += x;
+            self.base[column].1 += y;
+        }
+
+        match step {
+            MovementStep::Split { distance, .. } => self.split += distance / 2.0,
+            MovementStep::Merge { .. } => self.split = 0.0,
+            _ => {}
+        }
+
+        self.elapsed = 0.0;
+        self.step = (self.step + 1) % self.script.steps.len().max(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test that every command parses with its arguments
+    #[test]
+    fn test_parse_commands() {
+        let script = FormationScript::parse(
+            "sweep 6\nsine 20 2 4 # ripple\ncircle 40 3 3; split 120 1.5\nmerge 1.5\npause 1\naccelerate 1.2\nsweep",
+        )
+        .unwrap();
+
+        assert_eq!(
+            script.steps,
+            vec![
+                MovementStep::Sweep {
+                    duration: Some(6.0)
+                },
+                MovementStep::SineWave {
+                    amplitude: 20.0,
+                    period: 2.0,
+                    duration: 4.0
+                },
+                MovementStep::Circle {
+                    radius: 40.0,
+                    period: 3.0,
+                    duration: 3.0
+                },
+                MovementStep::Split {
+                    distance: 120.0,
+                    duration: 1.5
+                },
+                MovementStep::Merge { duration: 1.5 },
+                MovementStep::Pause { duration: 1.0 },
+                MovementStep::Accelerate { factor: 1.2 },
+                MovementStep::Sweep { duration: None },
+            ]
+        );
+    }
+
+    // Test that parse errors report the offending line
+    #[test]
+    fn test_parse_errors() {
+        let error = FormationScript::parse("sweep\nzigzag 3").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert!(error.message.contains("zigzag"));
+
+        assert!(FormationScript::parse("sine 10 2").is_err());
+        assert!(FormationScript::parse("pause -1").is_err());
+        assert!(FormationScript::parse("circle 10 0 2").is_err());
+        assert!(FormationScript::parse("# nothing\n").is_err());
+        assert!(FormationScript::parse("accelerate 2").is_err());
+    }
+
+    // Test that a script survives a round trip through its text form
+    #[test]
+    fn test_display_round_trip() {
+        let script =
+            FormationScript::parse("sweep 2; sine 10 1.5 3; accelerate 1.1; sweep").unwrap();
+        assert_eq!(FormationScript::parse(&script.to_string()).unwrap(), script);
+    }
+
+    // Test that the runner moves through timed steps and loops
+    #[test]
+    fn test_runner_advances_and_loops() {
+        let script = FormationScript::parse("sweep 1; pause 1").unwrap();
+        let mut runner = ScriptRunner::new(script, 11);
+
+        assert!(runner.advance(0.5).sweeping);
+        assert!(!runner.advance(0.6).sweeping);
+        assert_eq!(runner.step, 1);
+        runner.advance(1.0);
+        assert_eq!(runner.step, 0);
+    }
+
+    // Test that accelerate applies immediately and moves on
+    #[test]
+    fn test_runner_accelerate() {
+        let script = FormationScript::parse("accelerate 1.5; sweep").unwrap();
+        let mut runner = ScriptRunner::new(script, 11);
+
+        let tick = runner.advance(0.1);
+        assert_eq!(tick.speed_factor, 1.5);
+        assert!(tick.sweeping);
+        assert_eq!(runner.advance(0.1).speed_factor, 1.0);
+    }
+
+    // Test that a looping accelerate stops speeding the formation up at the limit
+    #[test]
+    fn test_runner_acceleration_is_bounded() {
+        let script = FormationScript::parse("pause 0.1; accelerate 1.5").unwrap();
+        let mut runner = ScriptRunner::new(script, 11);
+
+        let total: f32 = (0..100).map(|_| runner.advance(0.1).speed_factor).product();
+
+        assert!((total - MAX_ACCELERATION).abs() < 0.001);
+        assert_eq!(runner.advance(0.1).speed_factor, 1.0);
+    }
+
+    // Test that split moves the halves apart and merge brings them back
+    #[test]
+    fn test_runner_split_and_merge() {
+        let script = FormationScript::parse("split 100 1; merge 1; pause 1").unwrap();
+        let mut runner = ScriptRunner::new(script, 10);
+
+        runner.advance(1.0);
+        assert_eq!(runner.displacement(0), (-50.0, 0.0));
+        assert_eq!(runner.displacement(9), (50.0, 0.0));
+
+        runner.advance(0.5);
+        assert_eq!(runner.displacement(0), (-25.0, 0.0));
+
+        runner.advance(0.5);
+        assert_eq!(runner.displacement(0), (0.0, 0.0));
+        assert_eq!(runner.displacement(9), (0.0, 0.0));
+    }
+
+    // Test that a full circle ends where it started
+    #[test]
+    fn test_runner_circle() {
+        let script = FormationScript::parse("circle 40 2 2; pause 1").unwrap();
+        let mut runner = ScriptRunner::new(script, 11);
+
+        runner.advance(1.0);
+        let (x, y) = runner.displacement(3);
+        assert!(x.abs() < 0.01);
+        assert!((y - 80.0).abs() < 0.01);
+
+        runner.advance(1.0);
+        let (x, y) = runner.displacement(3);
+        assert!(x.abs() < 0.01 && y.abs() < 0.01);
+    }
+}
@@ -0,0 +1,180 @@
+use crate::game::difficulty::Difficulty;
+
+/// The number of letters in a player's initials
+pub const INITIALS_LENGTH: usize = 3;
+
+/// A single finished game on the leaderboard
+#[derive(Clone, PartialEq, Debug)]
+pub struct LeaderboardEntry {
+    pub initials: String,
+    pub score: i32,
+    pub level: i32,
+    pub difficulty: Difficulty,
+}
+
+/// The best scores of the session, highest first
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Leaderboard {
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+impl Leaderboard {
+    /// The number of entries kept on the leaderboard
+    pub const MAX_ENTRIES: usize = 10;
+
+    /// Checks whether a score is good enough to be added
+    /// # Arguments
+    /// * `score` - The score to check
+    /// # Returns -> `true` if the score would make it onto the leaderboard
+    pub fn qualifies(&self, score: i32) -> bool {
+        score > 0
+            && (self.entries.len() < Self::MAX_ENTRIES
+                || self.entries.last().is_some_and(|e| score > e.score))
+    }
+
+    /// Adds an entry, keeping the leaderboard sorted and trimmed
+    /// Ties are placed below the entries that were already there.
+    /// # Arguments
+    /// * `entry` - The finished game to record
+    /// # Returns -> The zero-based rank of the new entry, or `None` if it did not qualify
+    pub fn record(&mut self, entry: LeaderboardEntry) -> Option<usize> {
+        if !self.qualifies(entry.score) {
+            return None;
+        }
+
+        let rank = self
+            .entries
+            .iter()
+            .position(|e| entry.score > e.score)
+            .unwrap_or(self.entries.len());
+        self.entries.insert(rank, entry);
+        self.entries.truncate(Self::MAX_ENTRIES);
+
+        Some(rank)
+    }
+}
+
+/// The arcade-style initials entry shown when a score makes the leaderboard
+/// Each letter is picked by cycling through the alphabet.
+#[derive(Clone, PartialEq, Debug)]
+pub struct InitialsEntry {
+    pub letters: [char; INITIALS_LENGTH],
+    /// Index of the letter being changed
+    pub cursor: usize,
+}
+
+impl Default for InitialsEntry {
+    fn default() -> Self {
+        Self {
+            letters: ['A'; INITIALS_LENGTH],
+            cursor: 0,
+        }
+    }
+}
+
+impl InitialsEntry {
+    /// Cycles the letter under the cursor through A-Z, wrapping around
+    /// # Arguments
+    /// * `delta` - 1 for the next letter, -1 for the previous one
+    pub fn change_letter(&mut self, delta: i32) {
+        let letter = &mut self.letters[self.cursor];
+        let index = (*letter as i32 - 'A' as i32 + delta).rem_euclid(26);
+        *letter = char::from(b'A' + index as u8);
+    }
+
+    /// Moves the cursor to another letter, staying within the initials
+    /// # Arguments
+    /// * `delta` - Letters to move right (negative for left)
+    pub fn move_cursor(&mut self, delta: i32) {
+        self.cursor = (self.cursor as i32 + delta).clamp(0, INITIALS_LENGTH as i32 - 1) as usize;
+    }
+
+    /// Sets the letter under the cursor from a typed key and moves on to the next one
+    /// # Arguments
+    /// * `c` - The typed character; anything but a letter is ignored
+    pub fn type_letter(&mut self, c: char) {
+        if c.is_ascii_alphabetic() {
+            self.letters[self.cursor] = c.to_ascii_uppercase();
+            self.move_cursor(1);
+        }
+    }
+
+    /// Returns -> `true` when the cursor is on the last letter
+    pub fn on_last_letter(&self) -> bool {
+        self.cursor == INITIALS_LENGTH - 1
+    }
+
+    /// Returns -> the initials as a string, e.g. `"AAA"`
+    pub fn text(&self) -> String {
+        self.letters.iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(score: i32) -> LeaderboardEntry {
+        LeaderboardEntry {
+            initials: "AAA".to_string(),
+            score,
+            level: 1,
+            difficulty: Difficulty::Normal,
+        }
+    }
+
+    // Test that entries are kept in descending score order
+    #[test]
+    fn test_record_sorts_entries() {
+        let mut leaderboard = Leaderboard::default();
+        assert_eq!(leaderboard.record(entry(100)), Some(0));
+        assert_eq!(leaderboard.record(entry(300)), Some(0));
+        assert_eq!(leaderboard.record(entry(200)), Some(1));
+        assert_eq!(leaderboard.record(entry(200)), Some(2));
+
+        let scores: Vec<i32> = leaderboard.entries.iter().map(|e| e.score).collect();
+        assert_eq!(scores, vec![300, 200, 200, 100]);
+    }
+
+    // Test that the leaderboard never grows past its limit
+    #[test]
+    fn test_record_trims_to_max_entries() {
+        let mut leaderboard = Leaderboard::default();
+        for score in 1..=15 {
+            leaderboard.record(entry(score * 10));
+        }
+
+        assert_eq!(leaderboard.entries.len(), Leaderboard::MAX_ENTRIES);
+        assert!(!leaderboard.qualifies(60));
+        assert_eq!(leaderboard.record(entry(60)), None);
+        assert!(leaderboard.qualifies(70));
+    }
+
+    // Test that letters wrap around the alphabet and the cursor stays in range
+    #[test]
+    fn test_initials_entry() {
+        let mut initials = InitialsEntry::default();
+
+        initials.change_letter(-1);
+        assert_eq!(initials.text(), "ZAA");
+
+        initials.move_cursor(5);
+        assert!(initials.on_last_letter());
+        initials.change_letter(2);
+        assert_eq!(initials.text(), "ZAC");
+
+        initials.move_cursor(-5);
+        initials.type_letter('j');
+        initials.type_letter('7');
+        assert_eq!(initials.text(), "JAC");
+        assert_eq!(initials.cursor, 1);
+    }
+
+    // Test that empty games are not recorded
+    #[test]
+    fn test_zero_score_does_not_qualify() {
+        let mut leaderboard = Leaderboard::default();
+        assert_eq!(leaderboard.record(entry(0)), None);
+        assert!(leaderboard.entries.is_empty());
+    }
+}
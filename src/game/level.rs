@@ -0,0 +1,435 @@
+use crate::game::difficulty::DifficultyPreset;
+use crate::game::entities::alien::{
+    Alien, AlienFormation, AlienToughness, AlienType, FORMATION_COLS, GAME_WIDTH,
+};
+use crate::game::entities::shield::{Shield, ShieldType};
+use crate::game::formation_script::{FormationScript, ScriptRunner};
+use serde::{Deserialize, Serialize};
+
+/// Every this many levels the alien formation is replaced by a boss
+pub const BOSS_LEVEL_INTERVAL: i32 = 5;
+
+/// Checks whether a level is a boss encounter
+/// # Arguments
+/// * `level` - The level number (starting at 1)
+/// # Returns -> `true` for every `BOSS_LEVEL_INTERVAL`th level
+pub fn is_boss_level(level: i32) -> bool {
+    level > 0 && level % BOSS_LEVEL_INTERVAL == 0
+}
+
+/// Describes what happens to the player's shields when a new level begins
+/// Shields are created once per game; this policy decides whether the damage
+/// they took carries over into the next wave.
+/// The default (Keep) matches the original arcade behavior.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum ShieldPolicy {
+    /// Leave shields exactly as they were at the end of the previous level
+    #[default]
+    Keep,
+    /// Restore every shield to its original, undamaged shape
+    Regenerate,
+    /// Restore the given percentage (0-100) of each shield's destroyed segments
+    Repair(u32),
+    /// Take the shields away entirely
+    Remove,
+}
+
+/// Height of the top edge of every shield
+pub const SHIELD_Y: f32 = 500.0;
+
+/// An alien placed in a hand-made formation
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub struct AlienPlacement {
+    pub column: usize,
+    pub row: usize,
+    #[serde(rename = "type")]
+    pub alien_type: AlienType,
+    #[serde(default = "default_hit_points")]
+    pub hit_points: i32,
+    #[serde(default)]
+    pub armor: i32,
+}
+
+fn default_hit_points() -> i32 {
+    1
+}
+
+/// A shield placed in a hand-made level
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub struct ShieldPlacement {
+    pub x: f32,
+    #[serde(rename = "type")]
+    pub shield_type: ShieldType,
+}
+
+/// A hand-made level layout: which aliens and shields appear, and how fast
+/// the formation moves and fires. Levels without a layout use the standard
+/// formation and the difficulty preset's speeds.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct FormationLayout {
+    pub formation_speed: f32,
+    pub move_interval: f64,
+    /// Multiplies how often aliens shoot (1.0 is the preset's rate)
+    pub fire_rate: f64,
+    #[serde(default)]
+    pub aliens: Vec<AlienPlacement>,
+    #[serde(default)]
+    pub shields: Vec<ShieldPlacement>,
+}
+
+impl FormationLayout {
+    /// Builds the layout's alien formation
+    /// Empty grid cells are filled with dead aliens, so every alien's column
+    /// is still its index modulo `FORMATION_COLS`.
+    /// # Returns -> The formation, moving at the layout's speed
+    pub fn build_formation(&self) -> AlienFormation {
+        let rows = self.aliens.iter().map(|a| a.row + 1).max().unwrap_or(0);
+        let mut formation = AlienFormation::new(GAME_WIDTH);
+        formation.aliens.clear();
+
+        for row in 0..rows {
+            for column in 0..FORMATION_COLS {
+                let position = AlienFormation::slot_position(column, row);
+                let placement = self
+                    .aliens
+                    .iter()
+                    .find(|a| a.column == column && a.row == row);
+
+                let mut alien = Alien::new(
+                    position.x,
+                    position.y,
+                    placement.map_or(AlienType::Large, |p| p.alien_type),
+                );
+                match placement {
+                    Some(placement) => alien.set_toughness(AlienToughness {
+                        hit_points: placement.hit_points,
+                        armor: placement.armor,
+                    }),
+                    None => alien.is_alive = false,
+                }
+                formation.aliens.push(alien);
+            }
+        }
+
+        formation.speed = self.formation_speed;
+        formation.move_interval = self.move_interval;
+        formation
+    }
+
+    /// Returns -> the layout's shields, undamaged
+    pub fn build_shields(&self) -> Vec<Shield> {
+        self.shields
+            .iter()
+            .map(|s| Shield::new(s.x, SHIELD_Y, s.shield_type))
+            .collect()
+    }
+}
+
+/// Per-level settings that are applied when the level starts
+/// `row_toughness` lists the toughness of each formation row from the top;
+/// rows without an entry are standard one-hit aliens. `dive_interval` is the
+/// number of seconds between aliens breaking formation to dive, or `None`
+/// for no diving attacks. `movement` is the formation's movement script;
+/// without one the formation uses the classic sweep. `layout` replaces the
+/// standard formation with a hand-made one, such as a level from the editor.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct LevelConfig {
+    pub shield_policy: ShieldPolicy,
+    pub row_toughness: Vec<AlienToughness>,
+    pub dive_interval: Option<f64>,
+    pub movement: Option<FormationScript>,
+    pub layout: Option<FormationLayout>,
+}
+
+impl LevelConfig {
+    /// Builds the alien formation for this level
+    /// # Returns -> The layout's formation if there is one, otherwise a standard
+    /// formation with this level's toughness, plus the movement script
+    pub fn build_formation(&self) -> AlienFormation {
+        let mut formation = match &self.layout {
+            Some(layout) => layout.build_formation(),
+            None => AlienFormation::new(GAME_WIDTH),
+        };
+        formation.apply_toughness(&self.row_toughness);
+        formation.script = self
+            .movement
+            .clone()
+            .map(|script| ScriptRunner::new(script, FORMATION_COLS));
+        formation
+    }
+
+    /// Returns -> `true` if the level is fought against the boss
+    /// Levels with a hand-made layout never get a boss: their aliens are the level.
+    /// # Arguments
+    /// * `level` - The level number (starting at 1)
+    pub fn has_boss(&self, level: i32) -> bool {
+        self.layout.is_none() && is_boss_level(level)
+    }
+
+    /// Returns -> the formation's starting speed for this level
+    pub fn formation_speed(&self, preset: &DifficultyPreset) -> f32 {
+        self.layout
+            .as_ref()
+            .map_or(preset.formation_speed, |l| l.formation_speed)
+    }
+
+    /// Returns -> the formation's starting move interval for this level
+    pub fn move_interval(&self, preset: &DifficultyPreset) -> f64 {
+        self.layout
+            .as_ref()
+            .map_or(preset.move_interval, |l| l.move_interval)
+    }
+
+    /// Returns -> how much faster than the preset aliens shoot on this level
+    pub fn fire_rate(&self) -> f64 {
+        self.layout.as_ref().map_or(1.0, |l| l.fire_rate)
+    }
+}
+
+/// Looks up the configuration for a level
+/// Levels past the end of the list reuse the last entry, so a campaign only
+/// needs to describe the levels where something changes.
+/// # Arguments
+/// * `configs` - The level configurations, where index 0 is level 1
+/// * `level` - The level number (starting at 1)
+/// # Returns -> The matching LevelConfig, or the default if `configs` is empty
+pub fn level_config(configs: &[LevelConfig], level: i32) -> LevelConfig {
+    let index = (level.max(1) - 1) as usize;
+    configs
+        .get(index)
+        .or_else(|| configs.last())
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// Builds the level progression used by the standard game
+/// Shields are fully rebuilt for the early waves, patched up less and less
+/// as the game goes on, and finally taken away from level 8 onwards.
+/// From level 3 the top rows get tougher, gaining armor on later waves.
+/// Aliens start diving at the player from level 2, more often as levels go on.
+/// Some waves use movement scripts instead of the plain sweep.
+/// # Returns -> A vector of level configurations, starting with level 1
+pub fn default_level_configs() -> Vec<LevelConfig> {
+    let tough = |hit_points, armor| AlienToughness { hit_points, armor };
+    let standard = AlienToughness::default();
+    let policies = [
+        ShieldPolicy::Keep,
+        ShieldPolicy::Regenerate,
+        ShieldPolicy::Regenerate,
+        ShieldPolicy::Repair(50),
+        ShieldPolicy::Repair(50),
+        ShieldPolicy::Repair(25),
+        ShieldPolicy::Keep,
+        ShieldPolicy::Remove,
+    ];
+
+    let row_toughness = [
+        vec![],
+        vec![],
+        vec![tough(2, 0)],
+        vec![tough(2, 0)],
+        vec![tough(2, 0)],
+        vec![tough(2, 1), tough(2, 0)],
+        vec![tough(2, 1), tough(2, 0), tough(2, 0)],
+        vec![tough(3, 1), tough(2, 1), tough(2, 0), standard, tough(2, 0)],
+    ];
+
+    let dive_intervals = [
+        None,
+        Some(6.0),
+        Some(5.0),
+        Some(5.0),
+        Some(4.0),
+        Some(4.0),
+        Some(3.0),
+        Some(3.0),
+    ];
+
+    let movements = [
+        None,
+        None,
+        Some("sweep 6; sine 15 2 4"),
+        Some("sweep 5; split 160 2; pause 1.5; merge 2"),
+        None,
+        Some("sweep 4; circle 30 3 3; accelerate 1.05"),
+        Some("sweep 3; sine 20 1.5 3; split 120 1.5; merge 1.5"),
+        Some("sweep 3; circle 40 2.5 2.5; sine 20 1.5 3; accelerate 1.05"),
+    ];
+
+    policies
+        .iter()
+        .zip(row_toughness)
+        .zip(dive_intervals)
+        .zip(movements)
+        .map(
+            |(((&shield_policy, row_toughness), dive_interval), movement)| LevelConfig {
+                shield_policy,
+                row_toughness,
+                dive_interval,
+                movement: movement
+                    .map(|source| FormationScript::parse(source).expect("built-in script")),
+                layout: None,
+            },
+        )
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test that the default shield policy keeps damage
+    #[test]
+    fn test_shield_policy_default() {
+        assert_eq!(ShieldPolicy::default(), ShieldPolicy::Keep);
+        assert_eq!(LevelConfig::default().shield_policy, ShieldPolicy::Keep);
+    }
+
+    // Test that level_config picks the matching entry and reuses the last one
+    #[test]
+    fn test_level_config_lookup() {
+        let configs = vec![
+            LevelConfig {
+                shield_policy: ShieldPolicy::Keep,
+                ..Default::default()
+            },
+            LevelConfig {
+                shield_policy: ShieldPolicy::Repair(30),
+                ..Default::default()
+            },
+        ];
+
+        assert_eq!(level_config(&configs, 1).shield_policy, ShieldPolicy::Keep);
+        assert_eq!(
+            level_config(&configs, 2).shield_policy,
+            ShieldPolicy::Repair(30)
+        );
+        assert_eq!(
+            level_config(&configs, 10).shield_policy,
+            ShieldPolicy::Repair(30)
+        );
+    }
+
+    // Test that an empty configuration list falls back to the default
+    #[test]
+    fn test_level_config_empty() {
+        assert_eq!(level_config(&[], 3), LevelConfig::default());
+    }
+
+    // Test that the default campaign starts with standard aliens and adds tougher rows
+    #[test]
+    fn test_default_level_configs_toughness() {
+        let configs = default_level_configs();
+        assert!(level_config(&configs, 1).row_toughness.is_empty());
+        assert_eq!(level_config(&configs, 3).row_toughness[0].hit_points, 2);
+        assert!(level_config(&configs, 12).row_toughness[0].armor > 0);
+    }
+
+    // Test that build_formation applies the level's toughness and script
+    #[test]
+    fn test_build_formation() {
+        let config = LevelConfig {
+            row_toughness: vec![AlienToughness {
+                hit_points: 2,
+                armor: 0,
+            }],
+            movement: Some(FormationScript::parse("pause 1").unwrap()),
+            ..Default::default()
+        };
+
+        let formation = config.build_formation();
+        assert_eq!(formation.aliens[0].hit_points, 2);
+        assert!(formation.script.is_some());
+        assert!(LevelConfig::default().build_formation().script.is_none());
+    }
+
+    // Test that the built-in movement scripts all parse
+    #[test]
+    fn test_default_level_movements() {
+        let configs = default_level_configs();
+        assert!(configs[0].movement.is_none());
+        assert!(configs.iter().any(|c| c.movement.is_some()));
+    }
+
+    // Test that a layout builds a grid with dead aliens in the empty cells
+    #[test]
+    fn test_layout_build_formation() {
+        let layout = FormationLayout {
+            formation_speed: 30.0,
+            move_interval: 0.4,
+            fire_rate: 1.5,
+            aliens: vec![
+                AlienPlacement {
+                    column: 2,
+                    row: 0,
+                    alien_type: AlienType::Small,
+                    hit_points: 1,
+                    armor: 0,
+                },
+                AlienPlacement {
+                    column: 5,
+                    row: 1,
+                    alien_type: AlienType::Medium,
+                    hit_points: 2,
+                    armor: 1,
+                },
+            ],
+            shields: vec![ShieldPlacement {
+                x: 400.0,
+                shield_type: ShieldType::UppercaseT,
+            }],
+        };
+
+        let formation = layout.build_formation();
+        assert_eq!(formation.aliens.len(), 2 * FORMATION_COLS);
+        assert_eq!(formation.count_living(), 2);
+        assert!(formation.aliens[2].is_alive);
+        assert_eq!(
+            formation.aliens[FORMATION_COLS + 5].alien_type,
+            AlienType::Medium
+        );
+        assert_eq!(formation.aliens[FORMATION_COLS + 5].armor, 1);
+        assert_eq!(formation.speed, 30.0);
+
+        let shields = layout.build_shields();
+        assert_eq!(shields.len(), 1);
+        assert_eq!(shields[0].position.y, SHIELD_Y);
+
+        let config = LevelConfig {
+            layout: Some(layout),
+            ..Default::default()
+        };
+        let preset = crate::game::difficulty::Difficulty::Normal.preset();
+        assert_eq!(config.formation_speed(&preset), 30.0);
+        assert!(!config.has_boss(5));
+        assert!(LevelConfig::default().has_boss(5));
+        assert_eq!(config.move_interval(&preset), 0.4);
+        assert_eq!(config.fire_rate(), 1.5);
+        assert_eq!(LevelConfig::default().fire_rate(), 1.0);
+    }
+
+    // Test that every fifth level is a boss level
+    #[test]
+    fn test_is_boss_level() {
+        assert!(!is_boss_level(0));
+        assert!(!is_boss_level(1));
+        assert!(!is_boss_level(4));
+        assert!(is_boss_level(5));
+        assert!(is_boss_level(10));
+        assert!(!is_boss_level(11));
+    }
+
+    // Test that the default campaign removes shields on later levels
+    #[test]
+    fn test_default_level_configs() {
+        let configs = default_level_configs();
+        assert_eq!(
+            level_config(&configs, 2).shield_policy,
+            ShieldPolicy::Regenerate
+        );
+        assert_eq!(
+            level_config(&configs, 20).shield_policy,
+            ShieldPolicy::Remove
+        );
+    }
+}
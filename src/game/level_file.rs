@@ -0,0 +1,404 @@
+use crate::game::entities::alien::{AlienType, FORMATION_COLS, GAME_WIDTH};
+use crate::game::entities::shield::default_shields;
+use crate::game::formation_script::{FormationScript, ScriptError};
+use crate::game::level::{
+    AlienPlacement, FormationLayout, LevelConfig, ShieldPlacement, ShieldPolicy,
+};
+use crate::utils::i18n::{self, Language};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// The name the level editor saves its level under
+pub const CUSTOM_LEVEL_FILE: &str = "custom_level.toml";
+/// The most alien rows a level can have
+pub const MAX_ROWS: usize = 6;
+/// The most shields a level can have
+pub const MAX_SHIELDS: usize = 18;
+/// Formation speeds a level can use, in pixels per step
+pub const FORMATION_SPEED_RANGE: RangeInclusive<f32> = 5.0..=100.0;
+/// Fire rate multipliers a level can use
+pub const FIRE_RATE_RANGE: RangeInclusive<f64> = 0.25..=4.0;
+/// Hit points an alien can have
+const HIT_POINTS_RANGE: RangeInclusive<i32> = 1..=10;
+/// Armor an alien can have
+const ARMOR_RANGE: RangeInclusive<i32> = 0..=10;
+
+/// A level as it is stored on disk
+/// Level files are TOML. The movement script uses the same text format as
+/// the built-in levels.
+///
+/// ```toml
+/// name = "My level"
+/// movement = "sweep 5; split 160 2; merge 2"
+///
+/// [layout]
+/// formation_speed = 20.0
+/// move_interval = 0.5
+/// fire_rate = 1.0
+///
+/// [[layout.aliens]]
+/// column = 0
+/// row = 0
+/// type = "small"
+///
+/// [[layout.shields]]
+/// x = 100.0
+/// type = "C"
+/// ```
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct LevelFile {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub movement: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dive_interval: Option<f64>,
+    pub layout: FormationLayout,
+}
+
+/// A problem found while reading a level file
+#[derive(Clone, PartialEq, Debug)]
+pub enum LevelFileError {
+    /// The file is not valid TOML or is missing fields
+    Format(String),
+    /// The movement script could not be parsed
+    Script(ScriptError),
+    /// A value is out of range, such as an alien outside the grid
+    Invalid(InvalidValue),
+}
+
+/// A value in a level file that the level editor could not have made
+#[derive(Clone, PartialEq, Debug)]
+pub enum InvalidValue {
+    FormationSpeed,
+    FireRate,
+    MoveInterval,
+    DiveInterval,
+    AlienOutsideGrid { column: usize, row: usize },
+    AlienStats { column: usize, row: usize },
+    TooManyShields,
+    ShieldOffScreen { x: f32 },
+}
+
+impl LevelFileError {
+    /// Describes the problem for the player
+    /// # Arguments
+    /// * `language` - The language to describe it in
+    /// # Returns -> The message, from the `level_file` table of the catalog
+    pub fn message(&self, language: Language) -> String {
+        match self {
+            LevelFileError::Format(error) => {
+                i18n::format(language, "level_file.format", &[("error", error)])
+            }
+            LevelFileError::Script(error) => i18n::format(
+                language,
+                "level_file.script",
+                &[("line", &error.line), ("error", &error.message)],
+            ),
+            LevelFileError::Invalid(value) => value.message(language),
+        }
+    }
+}
+
+impl InvalidValue {
+    /// Describes the value for the player
+    /// # Arguments
+    /// * `language` - The language to describe it in
+    /// # Returns -> The message, from the `level_file` table of the catalog
+    pub fn message(&self, language: Language) -> String {
+        match self {
+            InvalidValue::FormationSpeed => i18n::format(
+                language,
+                "level_file.formation_speed",
+                &[
+                    ("min", FORMATION_SPEED_RANGE.start()),
+                    ("max", FORMATION_SPEED_RANGE.end()),
+                ],
+            ),
+            InvalidValue::FireRate => i18n::format(
+                language,
+                "level_file.fire_rate",
+                &[
+                    ("min", FIRE_RATE_RANGE.start()),
+                    ("max", FIRE_RATE_RANGE.end()),
+                ],
+            ),
+            InvalidValue::MoveInterval => i18n::text(language, "level_file.move_interval"),
+            InvalidValue::DiveInterval => i18n::text(language, "level_file.dive_interval"),
+            InvalidValue::AlienOutsideGrid { column, row } => i18n::format(
+                language,
+                "level_file.alien_outside_grid",
+                &[
+                    ("column", column),
+                    ("row", row),
+                    ("columns", &FORMATION_COLS),
+                    ("rows", &MAX_ROWS),
+                ],
+            ),
+            InvalidValue::AlienStats { column, row } => i18n::format(
+                language,
+                "level_file.alien_stats",
+                &[("column", column), ("row", row)],
+            ),
+            InvalidValue::TooManyShields => i18n::format(
+                language,
+                "level_file.too_many_shields",
+                &[("max", &MAX_SHIELDS)],
+            ),
+            InvalidValue::ShieldOffScreen { x } => {
+                i18n::format(language, "level_file.shield_off_screen", &[("x", x)])
+            }
+        }
+    }
+}
+
+/// Describes the problem in English
+impl fmt::Display for LevelFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message(Language::English))
+    }
+}
+
+impl Default for LevelFile {
+    /// Creates a level with the standard formation and shields
+    fn default() -> Self {
+        let aliens = (0..5)
+            .flat_map(|row| {
+                let alien_type = match row {
+                    0 => AlienType::Small,
+                    1 | 2 => AlienType::Medium,
+                    _ => AlienType::Large,
+                };
+                (0..FORMATION_COLS).map(move |column| AlienPlacement {
+                    column,
+                    row,
+                    alien_type,
+                    hit_points: 1,
+                    armor: 0,
+                })
+            })
+            .collect();
+
+        let shields = default_shields()
+            .iter()
+            .map(|s| ShieldPlacement {
+                x: s.position.x,
+                shield_type: s.shield_type,
+            })
+            .collect();
+
+        Self {
+            name: "Custom level".to_string(),
+            movement: None,
+            dive_interval: None,
+            layout: FormationLayout {
+                formation_speed: 20.0,
+                move_interval: 0.5,
+                fire_rate: 1.0,
+                aliens,
+                shields,
+            },
+        }
+    }
+}
+
+impl LevelFile {
+    /// Reads a level from its TOML text
+    /// # Arguments
+    /// * `source` - The contents of a level file
+    /// # Returns -> The level, or a LevelFileError describing what is wrong
+    pub fn parse(source: &str) -> Result<Self, LevelFileError> {
+        let level: Self =
+            toml::from_str(source).map_err(|e| LevelFileError::Format(e.message().to_string()))?;
+        level.to_config()?;
+        Ok(level)
+    }
+
+    /// Returns -> the level as TOML text, ready to be written to a file
+    pub fn to_toml(&self) -> String {
+        toml::to_string_pretty(self).expect("level files only contain TOML-compatible values")
+    }
+
+    /// Checks that every value is one the level editor could have made
+    /// # Returns -> An error describing the first value out of range
+    pub fn validate(&self) -> Result<(), LevelFileError> {
+        let invalid = |value: InvalidValue| Err(LevelFileError::Invalid(value));
+        let layout = &self.layout;
+
+        if !FORMATION_SPEED_RANGE.contains(&layout.formation_speed) {
+            return invalid(InvalidValue::FormationSpeed);
+        }
+        if !FIRE_RATE_RANGE.contains(&layout.fire_rate) {
+            return invalid(InvalidValue::FireRate);
+        }
+        if !(layout.move_interval > 0.0 && layout.move_interval.is_finite()) {
+            return invalid(InvalidValue::MoveInterval);
+        }
+        if let Some(interval) = self.dive_interval {
+            if !(interval > 0.0 && interval.is_finite()) {
+                return invalid(InvalidValue::DiveInterval);
+            }
+        }
+
+        for alien in &layout.aliens {
+            let (column, row) = (alien.column, alien.row);
+            if row >= MAX_ROWS || column >= FORMATION_COLS {
+                return invalid(InvalidValue::AlienOutsideGrid { column, row });
+            }
+            if !HIT_POINTS_RANGE.contains(&alien.hit_points) || !ARMOR_RANGE.contains(&alien.armor)
+            {
+                return invalid(InvalidValue::AlienStats { column, row });
+            }
+        }
+
+        if layout.shields.len() > MAX_SHIELDS {
+            return invalid(InvalidValue::TooManyShields);
+        }
+        if let Some(shield) = layout
+            .shields
+            .iter()
+            .find(|shield| !(0.0..GAME_WIDTH).contains(&shield.x))
+        {
+            return invalid(InvalidValue::ShieldOffScreen { x: shield.x });
+        }
+
+        Ok(())
+    }
+
+    /// Turns the level into the configuration the game runs
+    /// Shields are rebuilt from the layout every time the level starts.
+    /// # Returns -> The LevelConfig, or an error if a value or the movement script is invalid
+    pub fn to_config(&self) -> Result<LevelConfig, LevelFileError> {
+        self.validate()?;
+        let movement = self
+            .movement
+            .as_deref()
+            .map(FormationScript::parse)
+            .transpose()
+            .map_err(LevelFileError::Script)?;
+
+        Ok(LevelConfig {
+            shield_policy: ShieldPolicy::Regenerate,
+            row_toughness: Vec::new(),
+            dive_interval: self.dive_interval,
+            movement,
+            layout: Some(self.layout.clone()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test that the default level matches the standard game
+    #[test]
+    fn test_default_level() {
+        let level = LevelFile::default();
+        let config = level.to_config().unwrap();
+
+        assert_eq!(level.layout.aliens.len(), 55);
+        assert_eq!(level.layout.shields.len(), 6);
+        assert_eq!(config.build_formation().count_living(), 55);
+    }
+
+    // Test that a level survives a round trip through TOML
+    #[test]
+    fn test_round_trip() {
+        let mut level = LevelFile::default();
+        level.movement = Some("sweep 4; sine 10 2 3".to_string());
+        level.layout.fire_rate = 1.5;
+
+        let parsed = LevelFile::parse(&level.to_toml()).unwrap();
+        assert_eq!(parsed, level);
+    }
+
+    // Test that hand-written files are read with sensible defaults
+    #[test]
+    fn test_parse_hand_written() {
+        let level = LevelFile::parse(
+            r#"
+            name = "Tiny"
+
+            [layout]
+            formation_speed = 25.0
+            move_interval = 0.4
+            fire_rate = 2.0
+
+            [[layout.aliens]]
+            column = 3
+            row = 1
+            type = "medium"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(level.layout.aliens[0].alien_type, AlienType::Medium);
+        assert_eq!(level.layout.aliens[0].hit_points, 1);
+        assert!(level.layout.shields.is_empty());
+    }
+
+    // Test that broken files and scripts are reported
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(
+            LevelFile::parse("name = 3"),
+            Err(LevelFileError::Format(_))
+        ));
+
+        let mut level = LevelFile::default();
+        level.movement = Some("wobble".to_string());
+        assert!(matches!(
+            LevelFile::parse(&level.to_toml()),
+            Err(LevelFileError::Script(_))
+        ));
+    }
+
+    // Test that values the editor could not have made are rejected
+    #[test]
+    fn test_parse_out_of_range() {
+        let invalid = |edit: fn(&mut LevelFile)| {
+            let mut level = LevelFile::default();
+            edit(&mut level);
+            matches!(
+                LevelFile::parse(&level.to_toml()),
+                Err(LevelFileError::Invalid(_))
+            )
+        };
+
+        assert!(invalid(|level| level.layout.aliens[0].row = 100_000));
+        assert!(invalid(
+            |level| level.layout.aliens[0].column = FORMATION_COLS
+        ));
+        assert!(invalid(|level| level.layout.aliens[0].hit_points = 0));
+        assert!(invalid(|level| level.layout.fire_rate = 0.0));
+        assert!(invalid(|level| level.layout.move_interval = -1.0));
+        assert!(invalid(|level| level.layout.formation_speed = 1000.0));
+        assert!(invalid(|level| level.dive_interval = Some(0.0)));
+        assert!(invalid(|level| level.layout.shields[0].x = -50.0));
+        assert!(!invalid(|level| level.layout.aliens[0].row = MAX_ROWS - 1));
+    }
+
+    // Test that problems are described in the player's language
+    #[test]
+    fn test_error_messages() {
+        let error = LevelFileError::Invalid(InvalidValue::AlienOutsideGrid { column: 12, row: 2 });
+
+        assert_eq!(
+            error.to_string(),
+            "Invalid level: the alien at column 12, row 2 is outside the 11x6 grid"
+        );
+        assert!(error
+            .message(Language::French)
+            .contains("colonne 12, ligne 2"));
+
+        let script = LevelFile {
+            movement: Some("wobble".to_string()),
+            ..Default::default()
+        };
+        let message = LevelFile::parse(&script.to_toml())
+            .unwrap_err()
+            .message(Language::Spanish);
+        assert!(message.starts_with("Guion de movimiento no válido, línea 1"));
+    }
+}
@@ -1,11 +1,70 @@
+use crate::game::director::DifficultyDirector;
 use crate::game::entities::alien::AlienFormation;
-use crate::game::entities::mystery_ship::MysteryShip;
+use crate::game::entities::boss::{Boss, BOSS_DEFEAT_POINTS};
+use crate::game::entities::dive::{Dive, DiveOutcome, DIVE_SHOT_INTERVAL};
+use crate::game::entities::mystery_ship::{
+    arcade_points, MysteryShip, MysteryShipScoring, ARCADE_MIN_ALIENS_FOR_SPAWN,
+};
+use crate::game::entities::player::HIT_ANIMATION_DURATION;
 use crate::game::entities::projectile::Projectile;
+use crate::game::entities::shield::default_shields;
+use crate::game::events::GameEvent;
+use crate::game::leaderboard::{InitialsEntry, LeaderboardEntry};
+use crate::game::level::{level_config, ShieldPolicy};
+use crate::game::scoring::ScorePopup;
 use crate::game::state::{GameScreen, GameState};
+use crate::game::transition::LevelTransition;
+use crate::input::bindings::Action;
 use rand::Rng;
 
 const GAME_WIDTH: f32 = 1024.0;
 const GAME_HEIGHT: f32 = 700.0;
+const EXTRA_LIFE_FLASH_DURATION: f64 = 2.0;
+const MAX_DIVERS: usize = 2;
+const DIVE_RETURN_SPEED: f32 = 250.0;
+
+/// Starts a new input tick and reacts to the actions that change screens or
+/// pause the game: Confirm starts and restarts, Pause toggles the pause and
+/// Back leaves a playtest. When the screen changes, the keys typed this tick
+/// are dropped so the new screen doesn't handle the same keypress again.
+/// # Arguments
+/// * `game_state` - Mutable reference to the current game state
+pub fn handle_input(game_state: &mut GameState) {
+    game_state.key_states.begin_tick();
+    let screen = game_state.screen;
+
+    for action in game_state.key_states.just_pressed.clone() {
+        match (game_state.screen, action) {
+            (GameScreen::StartScreen, Action::Confirm) => {
+                *game_state = GameState {
+                    screen: GameScreen::Playing,
+                    ..game_state.restart()
+                };
+                break;
+            }
+            (GameScreen::Playing, Action::Pause) => game_state.paused = !game_state.paused,
+            (GameScreen::Playing, Action::Back) if game_state.playtesting => {
+                game_state.screen = GameScreen::LevelEditor;
+            }
+            (GameScreen::GameOver, Action::Confirm | Action::Back) if game_state.playtesting => {
+                game_state.screen = GameScreen::LevelEditor;
+            }
+            (GameScreen::HallOfFame, Action::Back) => game_state.screen = GameScreen::StartScreen,
+            (GameScreen::GameOver | GameScreen::HallOfFame, Action::Confirm) => {
+                *game_state = GameState {
+                    screen: GameScreen::Playing,
+                    ..game_state.restart()
+                };
+                break;
+            }
+            _ => {}
+        }
+    }
+
+    if game_state.screen != screen {
+        game_state.key_states.just_typed.clear();
+    }
+}
 
 /// Updates the player's position and state based on input and game conditions
 /// # Arguments
@@ -15,34 +74,100 @@ const GAME_HEIGHT: f32 = 700.0;
 pub fn update_player(game_state: &mut GameState, delta_time: f64) {
     let player = &mut game_state.player;
     let dt = delta_time as f32;
+    player.hit_animation_time = (player.hit_animation_time - delta_time).max(0.0);
 
-    if game_state.key_states.left {
+    let keys = &game_state.key_states;
+    if keys.left || keys.is_just_pressed(Action::MoveLeft) {
         player.move_left(dt, 0.0);
     }
-    if game_state.key_states.right {
+    if keys.right || keys.is_just_pressed(Action::MoveRight) {
         player.move_right(dt, GAME_WIDTH);
     }
+    if let Some(target_x) = game_state.key_states.pointer_x {
+        let steering = game_state.key_states.left || game_state.key_states.right;
+        if game_state.options.pointer_control && !steering {
+            player.move_towards(target_x, dt, 0.0, GAME_WIDTH);
+        }
+    }
 
     if game_state.invincibility_timer > 0.0 {
         game_state.invincibility_timer -= delta_time;
 
-        let elapsed_time = 3.0 - game_state.invincibility_timer;
+        let invincibility_duration = game_state
+            .options
+            .difficulty
+            .preset()
+            .invincibility_duration;
+        let elapsed_time = invincibility_duration - game_state.invincibility_timer;
 
         let interval = 0.1;
         let phase = (elapsed_time / interval) as i32;
-        player.is_hit = phase % 2 == 0;
+        player.is_hit = game_state.settings.reduced_motion || phase % 2 == 0;
     } else {
         player.is_hit = false;
     }
 }
 
 /// Updates the alien formation's position and state
+/// When the formation has a movement script, the script decides when the
+/// classic sweep runs and moves the columns for its other steps.
 /// # Arguments
 /// * `game_state` - Mutable reference to the current game state
 /// * `delta_time` - Time elapsed since last update in seconds
 pub fn update_aliens(game_state: &mut GameState, delta_time: f64) {
     let formation = &mut game_state.alien_formation;
 
+    for alien in &mut formation.aliens {
+        alien.hit_flash_timer = (alien.hit_flash_timer - delta_time).max(0.0);
+    }
+
+    let sweeping = match formation.script.take() {
+        Some(mut runner) => {
+            let columns = runner.columns.max(1);
+            let before: Vec<(f32, f32)> = (0..columns).map(|c| runner.displacement(c)).collect();
+            let tick = runner.advance(delta_time);
+
+            if tick.speed_factor != 1.0 {
+                formation.speed *= tick.speed_factor;
+                formation.move_interval /= tick.speed_factor as f64;
+            }
+
+            for (index, alien) in formation.aliens.iter_mut().enumerate() {
+                if !alien.is_alive {
+                    continue;
+                }
+
+                let column = index % columns;
+                let (old_x, old_y) = before[column];
+                let (new_x, new_y) = runner.displacement(column);
+                let position = alien.formation_position_mut();
+                position.x += new_x - old_x;
+                position.y += new_y - old_y;
+            }
+
+            formation.script = Some(runner);
+
+            if formation.get_lowest_y() >= 550.0 {
+                handle_invasion(game_state);
+                return;
+            }
+            tick.sweeping
+        }
+        None => true,
+    };
+
+    if sweeping {
+        sweep_formation(game_state, delta_time);
+    }
+}
+
+/// Moves the formation one step of the classic sweep when its move timer is up
+/// # Arguments
+/// * `game_state` - Mutable reference to the current game state
+/// * `delta_time` - Time elapsed since last update in seconds
+fn sweep_formation(game_state: &mut GameState, delta_time: f64) {
+    let formation = &mut game_state.alien_formation;
+
     formation.move_timer += delta_time;
 
     if formation.move_timer >= formation.move_interval {
@@ -58,10 +183,12 @@ pub fn update_aliens(game_state: &mut GameState, delta_time: f64) {
                 continue;
             }
 
-            alien.position.x += formation.direction * formation.speed;
+            let position = alien.formation_position_mut();
+
+            position.x += formation.direction * formation.speed;
 
             if formation.should_descend {
-                alien.position.y += 20.0;
+                position.y += 20.0;
             }
 
             alien.animation_frame += 1;
@@ -71,9 +198,142 @@ pub fn update_aliens(game_state: &mut GameState, delta_time: f64) {
 
         let lowest_y = formation.get_lowest_y();
         if lowest_y >= 550.0 {
-            game_state.game_over = true;
+            handle_invasion(game_state);
+        }
+    }
+}
+
+/// Sends aliens out of the formation on diving attacks and moves the divers
+/// Divers follow their curved path towards the player, shooting on the way
+/// down, and then either fly back to their slot or leave the screen.
+/// # Arguments
+/// * `game_state` - Mutable reference to the current game state
+/// * `delta_time` - Time elapsed since last update in seconds
+pub fn update_dives(game_state: &mut GameState, delta_time: f64) {
+    let config = level_config(&game_state.level_configs, game_state.level);
+
+    if let Some(interval) = config.dive_interval {
+        game_state.alien_formation.dive_timer += delta_time;
+        if game_state.alien_formation.dive_timer >= interval {
+            game_state.alien_formation.dive_timer = 0.0;
+            start_dive(game_state);
+        }
+    }
+
+    let dt = delta_time as f32;
+    let mut shots = Vec::new();
+    let mut escaped = 0;
+
+    for alien in &mut game_state.alien_formation.aliens {
+        let Some(dive) = &mut alien.dive else {
+            continue;
+        };
+
+        if !dive.returning {
+            dive.progress += dt / dive.duration;
+
+            if dive.is_attacking() {
+                alien.position = dive.path.point_at(dive.progress);
+
+                dive.shot_timer -= delta_time;
+                if dive.shot_timer <= 0.0 {
+                    dive.shot_timer = DIVE_SHOT_INTERVAL;
+                    shots.push(Projectile::alien(alien));
+                }
+                continue;
+            }
+
+            match dive.outcome {
+                DiveOutcome::Return => dive.returning = true,
+                DiveOutcome::Exit => {
+                    alien.position.y += DIVE_RETURN_SPEED * dt;
+                    if alien.position.y > GAME_HEIGHT {
+                        alien.is_alive = false;
+                        alien.dive = None;
+                        escaped += 1;
+                    }
+                    continue;
+                }
+            }
+        }
+
+        let dx = dive.slot.x - alien.position.x;
+        let dy = dive.slot.y - alien.position.y;
+        let distance = (dx * dx + dy * dy).sqrt();
+        let step = DIVE_RETURN_SPEED * dt;
+
+        if distance <= step {
+            alien.position = dive.slot.clone();
+            alien.dive = None;
+        } else {
+            alien.position.x += dx / distance * step;
+            alien.position.y += dy / distance * step;
         }
     }
+
+    game_state.alien_formation.aliens_escaped += escaped;
+    game_state.alien_projectiles.extend(shots);
+}
+
+/// Picks a random alien in the formation and sends it diving at the player
+/// Nothing happens if too many aliens are already diving.
+/// # Arguments
+/// * `game_state` - Mutable reference to the current game state
+/// # Returns -> `true` if an alien started a dive, `false` otherwise
+pub fn start_dive(game_state: &mut GameState) -> bool {
+    let formation = &mut game_state.alien_formation;
+    let diving = formation.aliens.iter().filter(|a| a.is_diving()).count();
+    if diving >= MAX_DIVERS {
+        return false;
+    }
+
+    let candidates: Vec<usize> = formation
+        .aliens
+        .iter()
+        .enumerate()
+        .filter(|(_, a)| a.is_alive && !a.is_diving())
+        .map(|(index, _)| index)
+        .collect();
+    if candidates.is_empty() {
+        return false;
+    }
+
+    let mut rng = rand::thread_rng();
+    let alien = &mut formation.aliens[candidates[rng.gen_range(0..candidates.len())]];
+    let outcome = if rng.gen_bool(0.25) {
+        DiveOutcome::Exit
+    } else {
+        DiveOutcome::Return
+    };
+
+    let target_x = game_state.player.position.x - alien.width / 2.0;
+    alien.dive = Some(Dive::new(
+        alien.position.clone(),
+        target_x,
+        game_state.player.position.y,
+        outcome,
+    ));
+    true
+}
+
+/// Handles the alien formation reaching the invasion line
+/// Depending on the difficulty this either ends the game, or costs the player
+/// a life and pushes the formation back up to the top of the screen.
+/// # Arguments
+/// * `game_state` - Mutable reference to the current game state
+pub fn handle_invasion(game_state: &mut GameState) {
+    if game_state.options.difficulty.preset().invasion_ends_game {
+        game_state.game_over = true;
+        return;
+    }
+
+    game_state.lives -= 1;
+    game_state.director.stats.lives_lost += 1;
+    game_state.alien_formation.retreat();
+
+    if game_state.lives <= 0 {
+        game_state.game_over = true;
+    }
 }
 
 /// Marks an alien as destroyed and updates game state accordingly
@@ -83,6 +343,10 @@ pub fn update_aliens(game_state: &mut GameState, delta_time: f64) {
 /// # Returns -> `true` if the alien was successfully destroyed, `false` otherwise
 
 pub fn destroy_alien(game_state: &mut GameState, alien_index: usize) -> bool {
+    let multiplier = score_multiplier(game_state);
+    let base_interval = level_config(&game_state.level_configs, game_state.level)
+        .move_interval(&game_state.options.difficulty.preset())
+        / adaptive_director(game_state).map_or(1.0, |d| d.tempo_scale);
     let formation = &mut game_state.alien_formation;
 
     if alien_index >= formation.aliens.len() || !formation.aliens[alien_index].is_alive {
@@ -92,24 +356,135 @@ pub fn destroy_alien(game_state: &mut GameState, alien_index: usize) -> bool {
     let alien = &mut formation.aliens[alien_index];
     alien.is_alive = false;
 
-    let points = alien.alien_type.points();
-    game_state.score += points;
-    if game_state.score > game_state.high_score {
-        game_state.high_score = game_state.score;
-    }
+    let points = alien.points() * multiplier;
+    let popup = ScorePopup::new(alien.position.x, alien.position.y, points);
 
     formation.aliens_killed += 1;
 
     let living_count = formation.count_living();
     if living_count > 0 {
-        let total_aliens = formation.aliens.len();
+        let total_aliens = living_count + formation.aliens_killed + formation.aliens_escaped;
         let percent_remaining = living_count as f64 / total_aliens as f64;
-        formation.move_interval = 0.1 + percent_remaining * 0.4;
+        formation.move_interval = 0.1 + percent_remaining * (base_interval - 0.1);
     }
 
+    add_score(game_state, points);
+    game_state.score_popups.push(popup);
+
     true
 }
 
+/// Applies one hit to an alien, destroying it once its hit points run out
+/// Armored aliens survive partial hits; they only flash and show damage.
+/// # Arguments
+/// * `game_state` - Mutable reference to the current game state
+/// * `alien_index` - Index of the alien that was hit
+/// # Returns -> `true` if the alien was destroyed by this hit, `false` otherwise
+pub fn hit_alien(game_state: &mut GameState, alien_index: usize) -> bool {
+    let Some(alien) = game_state.alien_formation.aliens.get_mut(alien_index) else {
+        return false;
+    };
+
+    if alien.take_hit() {
+        destroy_alien(game_state, alien_index)
+    } else {
+        false
+    }
+}
+
+/// Returns the adaptive difficulty director if the player has opted in to it
+/// # Arguments
+/// * `game_state` - Reference to the current game state
+/// # Returns -> `Some(director)` when adaptive difficulty is enabled, `None` otherwise
+pub fn adaptive_director(game_state: &GameState) -> Option<&DifficultyDirector> {
+    if game_state.options.adaptive_difficulty {
+        Some(&game_state.director)
+    } else {
+        None
+    }
+}
+
+/// Returns the multiplier applied to points scored right now
+/// This is the combo multiplier when combo scoring is enabled, and 1 otherwise.
+/// # Arguments
+/// * `game_state` - Reference to the current game state
+/// # Returns -> The current score multiplier
+pub fn score_multiplier(game_state: &GameState) -> i32 {
+    if game_state.options.combo_scoring {
+        game_state.combo.multiplier()
+    } else {
+        1
+    }
+}
+
+/// Adds points to the score, updating the high score and awarding extra lives
+/// Every source of points should go through this function so that extra life
+/// thresholds are never skipped.
+/// # Arguments
+/// * `game_state` - Mutable reference to the current game state
+/// * `points` - The number of points to add
+pub fn add_score(game_state: &mut GameState, points: i32) {
+    let previous_score = game_state.score;
+    game_state.score += points;
+    if game_state.score > game_state.high_score {
+        game_state.high_score = game_state.score;
+    }
+
+    let earned = game_state
+        .extra_life
+        .lives_earned(previous_score, game_state.score);
+
+    for _ in 0..earned {
+        if game_state.lives >= game_state.extra_life.max_lives {
+            break;
+        }
+
+        game_state.lives += 1;
+        game_state.extra_life_flash_timer = EXTRA_LIFE_FLASH_DURATION;
+        game_state.events.push(GameEvent::ExtraLife {
+            lives: game_state.lives,
+        });
+    }
+}
+
+/// Counts down timers for heads-up display effects such as the extra life flash
+/// and score popups
+/// # Arguments
+/// * `game_state` - Mutable reference to the current game state
+/// * `delta_time` - Time elapsed since last update in seconds
+pub fn update_hud(game_state: &mut GameState, delta_time: f64) {
+    if game_state.extra_life_flash_timer > 0.0 {
+        game_state.extra_life_flash_timer =
+            (game_state.extra_life_flash_timer - delta_time).max(0.0);
+    }
+
+    for popup in &mut game_state.score_popups {
+        popup.timer -= delta_time;
+        popup.position.y -= 30.0 * delta_time as f32;
+    }
+    game_state.score_popups.retain(|p| p.timer > 0.0);
+}
+
+/// Advances the starfield by the game clock, warping it when a level is cleared
+/// The starfield stays still when it is hidden or reduced motion is on.
+/// # Arguments
+/// * `game_state` - Mutable reference to the current game state
+/// * `delta_time` - Time elapsed since last update in seconds
+pub fn update_starfield(game_state: &mut GameState, delta_time: f64) {
+    if !game_state.settings.starfield || game_state.settings.reduced_motion {
+        return;
+    }
+
+    let cleared = game_state
+        .events
+        .iter()
+        .any(|event| matches!(event, GameEvent::LevelCleared { .. }));
+    if cleared {
+        game_state.starfield.warp();
+    }
+    game_state.starfield.update(delta_time);
+}
+
 /// Handles player shooting logic based on input and cooldown
 /// # Arguments
 /// * `game_state` - Mutable reference to the current game state
@@ -119,11 +494,15 @@ pub fn handle_player_shooting(game_state: &mut GameState, delta_time: f64) {
         game_state.player_shoot_cooldown -= delta_time;
     }
 
-    if game_state.key_states.shift && game_state.player_shoot_cooldown <= 0.0 {
+    let fire = game_state.key_states.shift || game_state.key_states.is_just_pressed(Action::Fire);
+    if fire && game_state.player_shoot_cooldown <= 0.0 {
         let projectile = Projectile::player(&game_state.player);
         game_state.player_projectiles.push(projectile);
+        game_state.shots_fired += 1;
+        game_state.director.stats.shots_fired += 1;
 
-        game_state.player_shoot_cooldown = 0.5;
+        game_state.player_shoot_cooldown =
+            game_state.options.difficulty.preset().player_shoot_cooldown;
     }
 }
 
@@ -133,6 +512,11 @@ pub fn handle_player_shooting(game_state: &mut GameState, delta_time: f64) {
 /// * `delta_time` - Time elapsed since last update in seconds
 
 pub fn handle_alien_shooting(game_state: &mut GameState, delta_time: f64) {
+    let (fire_rate_scale, projectile_speed_scale) = adaptive_director(game_state)
+        .map_or((1.0, 1.0), |d| {
+            (d.fire_rate_scale, d.projectile_speed_scale)
+        });
+
     if game_state.alien_shoot_cooldown > 0.0 {
         game_state.alien_shoot_cooldown -= delta_time;
     }
@@ -150,10 +534,16 @@ pub fn handle_alien_shooting(game_state: &mut GameState, delta_time: f64) {
             let random_index = rng.gen_range(0..living_aliens.len());
             let shooting_alien = living_aliens[random_index];
 
-            let projectile = Projectile::alien(shooting_alien);
+            let mut projectile = Projectile::alien(shooting_alien);
+            projectile.velocity *= projectile_speed_scale as f32;
             game_state.alien_projectiles.push(projectile);
 
-            game_state.alien_shoot_cooldown = rng.gen_range(0.5..2.0);
+            let (min_cooldown, max_cooldown) =
+                game_state.options.difficulty.preset().alien_shoot_cooldown;
+            let level_fire_rate =
+                level_config(&game_state.level_configs, game_state.level).fire_rate();
+            game_state.alien_shoot_cooldown =
+                rng.gen_range(min_cooldown..max_cooldown) / (fire_rate_scale * level_fire_rate);
         }
     }
 }
@@ -174,6 +564,14 @@ pub fn update_projectiles(game_state: &mut GameState, delta_time: f64) {
         projectile.update(dt);
     }
 
+    if game_state
+        .player_projectiles
+        .iter()
+        .any(|p| p.is_off_screen(0.0))
+    {
+        game_state.combo.reset();
+    }
+
     game_state
         .player_projectiles
         .retain(|p| !p.is_off_screen(0.0));
@@ -183,17 +581,204 @@ pub fn update_projectiles(game_state: &mut GameState, delta_time: f64) {
 }
 
 /// Checks if the current level is complete and prepares the next level if needed
+/// Clearing a level clears the projectiles in flight and the mystery ship, and
+/// starts a level transition with the next wave waiting above the playfield.
 /// # Arguments
 /// * `game_state` - Mutable reference to the current game state
 
 pub fn check_level_completion(game_state: &mut GameState) {
-    if game_state.alien_formation.count_living() == 0 {
+    let cleared = match &game_state.boss {
+        Some(boss) => boss.is_defeated(),
+        None => game_state.alien_formation.count_living() == 0,
+    };
+
+    if cleared {
+        let points = game_state.score - game_state.wave_start_score;
+        let stats = game_state.director.stats.clone();
+        if game_state.options.adaptive_difficulty {
+            game_state.director.end_level(game_state.level);
+        } else {
+            game_state.director.stats = Default::default();
+        }
+
+        game_state.events.push(GameEvent::LevelCleared {
+            level: game_state.level,
+        });
         game_state.level += 1;
 
-        let mut new_formation = AlienFormation::new(GAME_WIDTH);
-        new_formation.speed *= 1.0 + (game_state.level as f32 * 0.1);
-        new_formation.move_interval *= 0.9;
-        game_state.alien_formation = new_formation;
+        let preset = game_state.options.difficulty.preset();
+        let config = level_config(&game_state.level_configs, game_state.level);
+        let boss_level = config.has_boss(game_state.level);
+        let mut new_formation = config.build_formation();
+        new_formation.speed =
+            config.formation_speed(&preset) * (1.0 + game_state.level as f32 * 0.1);
+        new_formation.move_interval = config.move_interval(&preset) * 0.9
+            / adaptive_director(game_state).map_or(1.0, |d| d.tempo_scale);
+
+        if boss_level {
+            game_state.boss = Some(Boss::new(GAME_WIDTH));
+            game_state.alien_formation = AlienFormation::default();
+        } else {
+            game_state.boss = None;
+            game_state.alien_formation = new_formation;
+        }
+
+        apply_shield_policy(game_state);
+
+        game_state.player_projectiles.clear();
+        game_state.alien_projectiles.clear();
+        game_state.mystery_ship = None;
+        let slide_distance = game_state.wave_bottom();
+        game_state.shift_wave(-slide_distance);
+        game_state.transition = Some(LevelTransition::new(
+            game_state.level - 1,
+            points,
+            stats,
+            slide_distance,
+        ));
+        game_state.wave_start_score = game_state.score;
+    }
+}
+
+/// Advances the level transition, sliding the new wave into place
+/// Play resumes once the wave has arrived.
+/// # Arguments
+/// * `game_state` - Mutable reference to the current game state
+/// * `delta_time` - Time elapsed since last update in seconds
+pub fn update_level_transition(game_state: &mut GameState, delta_time: f64) {
+    let Some(transition) = game_state.transition.as_mut() else {
+        return;
+    };
+
+    let moved = transition.update(delta_time);
+    let finished = transition.is_finished();
+    game_state.shift_wave(moved);
+    if finished {
+        game_state.transition = None;
+    }
+}
+
+/// Applies the current level's shield policy to the player's shields
+/// Regenerating after the shields were removed puts a fresh set back in place.
+/// # Arguments
+/// * `game_state` - Mutable reference to the current game state
+pub fn apply_shield_policy(game_state: &mut GameState) {
+    let config = level_config(&game_state.level_configs, game_state.level);
+
+    match config.shield_policy {
+        ShieldPolicy::Keep => {}
+        ShieldPolicy::Regenerate => match &config.layout {
+            Some(layout) => game_state.shields = layout.build_shields(),
+            None => {
+                if game_state.shields.is_empty() {
+                    game_state.shields = default_shields();
+                }
+                for shield in &mut game_state.shields {
+                    shield.regenerate();
+                }
+            }
+        },
+        ShieldPolicy::Repair(percent) => {
+            for shield in &mut game_state.shields {
+                shield.repair(percent);
+            }
+        }
+        ShieldPolicy::Remove => game_state.shields.clear(),
+    }
+}
+
+/// Moves the boss, advances its attack phase and fires its cannons
+/// # Arguments
+/// * `game_state` - Mutable reference to the current game state
+/// * `delta_time` - Time elapsed since last update in seconds
+pub fn update_boss(game_state: &mut GameState, delta_time: f64) {
+    let Some(boss) = &mut game_state.boss else {
+        return;
+    };
+    if boss.is_defeated() {
+        return;
+    }
+
+    boss.update(delta_time as f32, GAME_WIDTH);
+
+    let phase = boss.phase_for_health();
+    if phase != boss.phase {
+        boss.phase = phase;
+        boss.attack_timer = 0.5;
+        game_state
+            .events
+            .push(GameEvent::BossPhaseChanged { phase });
+    }
+
+    boss.attack_timer -= delta_time;
+    if boss.attack_timer <= 0.0 {
+        boss.attack_timer = boss.phase.attack_interval();
+
+        for (x, y) in boss.cannon_positions() {
+            for offset in boss.phase.shot_spread() {
+                let projectile = Projectile::new(x + offset - 1.5, y, false);
+                game_state.alien_projectiles.push(projectile);
+            }
+        }
+    }
+}
+
+/// Checks player projectiles against the boss and applies the damage
+/// # Arguments
+/// * `game_state` - Mutable reference to the current game state
+fn check_boss_collisions(game_state: &mut GameState) {
+    let Some(boss) = &mut game_state.boss else {
+        return;
+    };
+
+    let mut projectiles_to_remove = Vec::new();
+    let mut points_to_add = 0;
+    let was_defeated = boss.is_defeated();
+
+    for (proj_idx, projectile) in game_state.player_projectiles.iter().enumerate() {
+        if boss.is_defeated() {
+            break;
+        }
+
+        if let Some(segment) = boss.segment_at(
+            projectile.position.x,
+            projectile.position.y,
+            projectile.width,
+            projectile.height,
+        ) {
+            projectiles_to_remove.push(proj_idx);
+            points_to_add += boss.damage_segment(segment);
+        }
+    }
+
+    let defeated_now = !was_defeated && boss.is_defeated();
+    if defeated_now {
+        points_to_add += BOSS_DEFEAT_POINTS;
+    }
+    let (popup_x, popup_y) = (
+        boss.position.x + boss.width / 2.0,
+        boss.position.y + boss.height,
+    );
+
+    for idx in projectiles_to_remove.iter().rev() {
+        game_state.player_projectiles.remove(*idx);
+    }
+
+    for _ in &projectiles_to_remove {
+        game_state.combo.register_hit();
+        game_state.director.stats.hits += 1;
+    }
+
+    if points_to_add > 0 {
+        let points = points_to_add * score_multiplier(game_state);
+        add_score(game_state, points);
+        game_state
+            .score_popups
+            .push(ScorePopup::new(popup_x, popup_y, points));
+    }
+
+    if defeated_now {
+        game_state.events.push(GameEvent::BossDefeated);
     }
 }
 
@@ -211,11 +796,43 @@ pub fn check_game_over_conditions(game_state: &mut GameState) {
     let lowest_alien_y = game_state.alien_formation.get_lowest_y();
 
     if lowest_alien_y >= player_y - 50.0 {
-        game_state.game_over = true;
-        game_state.screen = GameScreen::GameOver;
+        handle_invasion(game_state);
+        if game_state.game_over {
+            game_state.screen = GameScreen::GameOver;
+        }
     }
 }
 
+/// Ends the current game, showing the game over screen and recording the score
+/// This should be called once, on the update where `game_over` becomes true.
+/// Scores from level editor playtests are not recorded.
+/// # Arguments
+/// * `game_state` - Mutable reference to the current game state
+pub fn finish_game(game_state: &mut GameState) {
+    game_state.screen = GameScreen::GameOver;
+    if game_state.playtesting {
+        return;
+    }
+    if game_state.leaderboard.qualifies(game_state.score) {
+        game_state.initials = InitialsEntry::default();
+        game_state.screen = GameScreen::InitialsEntry;
+    }
+}
+
+/// Records the finished game on the leaderboard under the entered initials
+/// and moves on to the Hall of Fame with the new entry highlighted
+/// # Arguments
+/// * `game_state` - Mutable reference to the current game state
+pub fn submit_initials(game_state: &mut GameState) {
+    game_state.hall_of_fame_rank = game_state.leaderboard.record(LeaderboardEntry {
+        initials: game_state.initials.text(),
+        score: game_state.score,
+        level: game_state.level,
+        difficulty: game_state.options.difficulty,
+    });
+    game_state.screen = GameScreen::HallOfFame;
+}
+
 /// Updates the mystery ship's state or spawns a new one
 /// # Arguments
 /// * `game_state` - Mutable reference to the current game state
@@ -234,7 +851,14 @@ pub fn update_mystery_ship(game_state: &mut GameState) {
             let mut rng = rand::thread_rng();
             game_state.mystery_ship_timer = rng.gen_range(15.0..30.0);
 
-            if rng.gen_bool(0.7) {
+            let enough_aliens = match game_state.options.mystery_ship_scoring {
+                MysteryShipScoring::Random => true,
+                MysteryShipScoring::ShotCount => {
+                    game_state.alien_formation.count_living() >= ARCADE_MIN_ALIENS_FOR_SPAWN
+                }
+            };
+
+            if enough_aliens && rng.gen_bool(0.7) {
                 let mut ship = MysteryShip::new();
 
                 if rng.gen_bool(0.5) {
@@ -295,16 +919,26 @@ pub fn check_projectile_collisions(game_state: &mut GameState) {
 
     for (proj_idx, projectile) in game_state.player_projectiles.iter().enumerate() {
         if let Some(points) = check_mystery_ship_hit(&mut game_state.mystery_ship, projectile) {
+            let points = match game_state.options.mystery_ship_scoring {
+                MysteryShipScoring::Random => points,
+                MysteryShipScoring::ShotCount => arcade_points(game_state.shots_fired),
+            };
             mystery_ship_hits.push(proj_idx);
-            points_to_add += points;
+            game_state.combo.register_hit();
+            game_state.director.stats.hits += 1;
+            points_to_add += points * score_multiplier(game_state);
             game_state.mystery_ship_timer = 5.0;
         }
     }
 
     if points_to_add > 0 {
-        game_state.score += points_to_add;
-        if game_state.score > game_state.high_score {
-            game_state.high_score = game_state.score;
+        add_score(game_state, points_to_add);
+        if let Some(ship) = &game_state.mystery_ship {
+            game_state.score_popups.push(ScorePopup::new(
+                ship.position.x,
+                ship.position.y,
+                points_to_add,
+            ));
         }
     }
 
@@ -331,6 +965,8 @@ pub fn check_projectile_collisions(game_state: &mut GameState) {
         })
     });
 
+    check_boss_collisions(game_state);
+
     for (proj_idx, projectile) in game_state.player_projectiles.iter().enumerate() {
         for (alien_idx, alien) in game_state.alien_formation.aliens.iter().enumerate() {
             if !alien.is_alive {
@@ -350,7 +986,9 @@ pub fn check_projectile_collisions(game_state: &mut GameState) {
     }
 
     for alien_idx in aliens_to_destroy {
-        destroy_alien(game_state, alien_idx);
+        game_state.combo.register_hit();
+        game_state.director.stats.hits += 1;
+        hit_alien(game_state, alien_idx);
     }
 
     for idx in projectiles_to_remove.iter().rev() {
@@ -408,16 +1046,7 @@ fn check_player_collisions(game_state: &mut GameState) {
             && projectile.position.y + projectile.height > game_state.player.position.y
         {
             alien_projectiles_to_remove.push(proj_idx);
-
-            game_state.player.is_hit = true;
-            game_state.invincibility_timer = 3.0;
-
-            game_state.lives -= 1;
-
-            if game_state.lives <= 0 {
-                game_state.game_over = true;
-            }
-
+            hit_player(game_state);
             break;
         }
     }
@@ -427,12 +1056,63 @@ fn check_player_collisions(game_state: &mut GameState) {
             game_state.alien_projectiles.remove(*idx);
         }
     }
+
+    check_diver_collisions(game_state);
+}
+
+/// Checks whether a diving alien has flown into the player's ship
+/// The alien is destroyed in the crash and the player loses a life.
+/// # Arguments
+/// * `game_state` - Mutable reference to the current game state
+fn check_diver_collisions(game_state: &mut GameState) {
+    if game_state.invincibility_timer > 0.0 {
+        return;
+    }
+
+    let player = &game_state.player;
+    let player_x_pixels = player.position.x - 25.0;
+    let crashed = game_state.alien_formation.aliens.iter().position(|alien| {
+        alien.is_alive
+            && alien.is_diving()
+            && alien.position.x < player_x_pixels + player.width
+            && alien.position.x + alien.width > player_x_pixels
+            && alien.position.y < player.position.y + player.height
+            && alien.position.y + alien.height > player.position.y
+    });
+
+    if let Some(alien_idx) = crashed {
+        game_state.alien_formation.aliens[alien_idx].dive = None;
+        destroy_alien(game_state, alien_idx);
+        hit_player(game_state);
+    }
+}
+
+/// Costs the player a life and starts their invincibility period
+/// # Arguments
+/// * `game_state` - Mutable reference to the current game state
+fn hit_player(game_state: &mut GameState) {
+    game_state.player.is_hit = true;
+    game_state.player.hit_animation_time = HIT_ANIMATION_DURATION;
+    game_state.invincibility_timer = game_state
+        .options
+        .difficulty
+        .preset()
+        .invincibility_duration;
+    game_state.combo.reset();
+
+    game_state.lives -= 1;
+    game_state.director.stats.lives_lost += 1;
+
+    if game_state.lives <= 0 {
+        game_state.game_over = true;
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::game::entities::player::Player;
+    use crate::utils::position::Position;
     // use crate::game::entities::shield::{Shield, ShieldType};
     // use crate::input::key_states::KeyStates;
 
@@ -462,6 +1142,130 @@ mod tests {
         assert!(game_state.player.position.x > initial_x);
     }
 
+    // Test that the ship follows the pointer only in pointer control mode
+    #[test]
+    fn test_player_follows_pointer() {
+        let mut game_state = GameState::default();
+        game_state.key_states.pointer_x = Some(900.0);
+
+        let initial_x = game_state.player.position.x;
+        update_player(&mut game_state, 0.1);
+        assert_eq!(game_state.player.position.x, initial_x);
+
+        game_state.options.pointer_control = true;
+        update_player(&mut game_state, 0.1);
+        assert_eq!(
+            game_state.player.position.x,
+            initial_x + game_state.player.speed * 0.1
+        );
+
+        game_state.key_states.pointer_x = Some(5000.0);
+        update_player(&mut game_state, 100.0);
+        assert_eq!(
+            game_state.player.position.x,
+            GAME_WIDTH - game_state.player.width / 2.0
+        );
+    }
+
+    // Test that the ship flickers while invincible, unless motion is reduced
+    #[test]
+    fn test_player_hit_flicker() {
+        let mut game_state = GameState {
+            invincibility_timer: 3.0,
+            ..Default::default()
+        };
+
+        update_player(&mut game_state, 0.05);
+        assert!(game_state.player.is_hit);
+        update_player(&mut game_state, 0.1);
+        assert!(!game_state.player.is_hit);
+
+        game_state.settings.reduced_motion = true;
+        update_player(&mut game_state, 0.1);
+        assert!(game_state.player.is_hit);
+        update_player(&mut game_state, 0.1);
+        assert!(game_state.player.is_hit);
+
+        game_state.invincibility_timer = 0.0;
+        update_player(&mut game_state, 0.1);
+        assert!(!game_state.player.is_hit);
+    }
+
+    // Test that the wreck is shown for a moment after a hit, independently of the flicker
+    #[test]
+    fn test_player_wreck() {
+        let mut game_state = GameState {
+            lives: 3,
+            ..Default::default()
+        };
+        hit_player(&mut game_state);
+        assert!(game_state.player.is_wrecked());
+
+        update_player(&mut game_state, 0.1);
+        assert!(game_state.player.is_wrecked());
+        assert!(!game_state.player.is_hit);
+
+        update_player(&mut game_state, HIT_ANIMATION_DURATION);
+        assert!(!game_state.player.is_wrecked());
+        assert!(game_state.invincibility_timer > 0.0);
+
+        game_state.settings.reduced_motion = true;
+        update_player(&mut game_state, 0.1);
+        assert!(game_state.player.is_hit);
+        assert!(!game_state.player.is_wrecked());
+    }
+
+    // Test that a tap between ticks still fires a shot
+    #[test]
+    fn test_tap_fires_between_ticks() {
+        let mut game_state = GameState {
+            screen: GameScreen::Playing,
+            ..Default::default()
+        };
+        game_state.key_states.queue(Action::Fire, true);
+        game_state.key_states.queue(Action::Fire, false);
+
+        handle_input(&mut game_state);
+        handle_player_shooting(&mut game_state, 0.016);
+
+        assert!(!game_state.key_states.shift);
+        assert_eq!(game_state.player_projectiles.len(), 1);
+    }
+
+    // Test that actions start, pause and restart the game
+    #[test]
+    fn test_handle_input() {
+        let mut game_state = GameState::default();
+
+        game_state.key_states.queue(Action::Confirm, true);
+        handle_input(&mut game_state);
+        assert_eq!(game_state.screen, GameScreen::Playing);
+
+        game_state.key_states.queue(Action::MoveLeft, true);
+        handle_input(&mut game_state);
+        assert!(game_state.key_states.left);
+        game_state.key_states.queue(Action::MoveLeft, false);
+        handle_input(&mut game_state);
+        assert!(!game_state.key_states.left);
+
+        game_state.key_states.queue(Action::Pause, true);
+        game_state.key_states.queue(Action::Pause, false);
+        handle_input(&mut game_state);
+        assert!(game_state.paused);
+        handle_input(&mut game_state);
+        assert!(game_state.paused);
+        game_state.key_states.queue(Action::Pause, true);
+        handle_input(&mut game_state);
+        assert!(!game_state.paused);
+
+        game_state.screen = GameScreen::GameOver;
+        game_state.score = 500;
+        game_state.key_states.queue(Action::Confirm, true);
+        handle_input(&mut game_state);
+        assert_eq!(game_state.screen, GameScreen::Playing);
+        assert_eq!(game_state.score, 0);
+    }
+
     // Test that player shooting creates a projectile
     #[test]
     fn test_player_shooting() {
@@ -474,6 +1278,544 @@ mod tests {
 
         assert_eq!(game_state.player_projectiles.len(), 1);
         assert!(game_state.player_shoot_cooldown > 0.0);
+        assert_eq!(game_state.shots_fired, 1);
+    }
+
+    // Test that arcade scoring awards mystery ship points by shot count
+    #[test]
+    fn test_mystery_ship_shot_count_scoring() {
+        use crate::game::options::GameOptions;
+
+        let mut ship = MysteryShip::new();
+        ship.active = true;
+        ship.points = 50;
+        ship.position.x = 100.0;
+
+        let mut game_state = GameState {
+            shots_fired: 23,
+            options: GameOptions {
+                mystery_ship_scoring: MysteryShipScoring::ShotCount,
+                ..Default::default()
+            },
+            mystery_ship: Some(ship),
+            player_projectiles: vec![Projectile::new(110.0, 15.0, true)],
+            ..Default::default()
+        };
+
+        check_projectile_collisions(&mut game_state);
+
+        assert_eq!(game_state.score, 300);
+    }
+
+    // Test that no mystery ship spawns in arcade mode with fewer than eight aliens
+    #[test]
+    fn test_mystery_ship_needs_eight_aliens_in_arcade_mode() {
+        use crate::game::options::GameOptions;
+
+        let mut formation = AlienFormation::new(GAME_WIDTH);
+        for alien in formation.aliens.iter_mut().skip(7) {
+            alien.is_alive = false;
+        }
+
+        let mut game_state = GameState {
+            options: GameOptions {
+                mystery_ship_scoring: MysteryShipScoring::ShotCount,
+                ..Default::default()
+            },
+            alien_formation: formation,
+            ..Default::default()
+        };
+
+        for _ in 0..20 {
+            game_state.mystery_ship_timer = 0.0;
+            update_mystery_ship(&mut game_state);
+            assert!(game_state.mystery_ship.is_none());
+        }
+    }
+
+    // Test that the player shoot cooldown comes from the difficulty preset
+    #[test]
+    fn test_player_shoot_cooldown_uses_difficulty() {
+        use crate::game::difficulty::Difficulty;
+        use crate::game::options::GameOptions;
+
+        let mut game_state = GameState {
+            options: GameOptions {
+                difficulty: Difficulty::Easy,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        game_state.key_states.shift = true;
+
+        handle_player_shooting(&mut game_state, 0.1);
+
+        assert_eq!(
+            game_state.player_shoot_cooldown,
+            Difficulty::Easy.preset().player_shoot_cooldown
+        );
+    }
+
+    // Test that alien shots are spaced within the difficulty's cooldown range
+    #[test]
+    fn test_alien_shoot_cooldown_uses_difficulty() {
+        use crate::game::difficulty::Difficulty;
+        use crate::game::options::GameOptions;
+
+        for difficulty in Difficulty::ALL {
+            let mut game_state = GameState {
+                options: GameOptions {
+                    difficulty,
+                    ..Default::default()
+                },
+                alien_formation: AlienFormation::new(GAME_WIDTH),
+                ..Default::default()
+            };
+
+            handle_alien_shooting(&mut game_state, 0.1);
+
+            let (min, max) = difficulty.preset().alien_shoot_cooldown;
+            assert_eq!(game_state.alien_projectiles.len(), 1);
+            assert!(game_state.alien_shoot_cooldown >= min);
+            assert!(game_state.alien_shoot_cooldown < max);
+        }
+    }
+
+    // Test that the adaptive director speeds up alien fire and projectiles when enabled
+    #[test]
+    fn test_adaptive_director_scales_alien_shooting() {
+        use crate::game::director::DifficultyDirector;
+        use crate::game::options::GameOptions;
+
+        let mut game_state = GameState {
+            options: GameOptions {
+                adaptive_difficulty: true,
+                ..Default::default()
+            },
+            director: DifficultyDirector {
+                fire_rate_scale: 1.5,
+                projectile_speed_scale: 1.5,
+                ..Default::default()
+            },
+            alien_formation: AlienFormation::new(GAME_WIDTH),
+            ..Default::default()
+        };
+
+        handle_alien_shooting(&mut game_state, 0.1);
+
+        let (_, max_cooldown) = game_state.options.difficulty.preset().alien_shoot_cooldown;
+        assert!(game_state.alien_shoot_cooldown < max_cooldown / 1.5);
+        assert_eq!(game_state.alien_projectiles[0].velocity, 200.0 * 1.5);
+    }
+
+    // Test that the director is ignored unless the player opts in
+    #[test]
+    fn test_adaptive_director_disabled() {
+        use crate::game::director::DifficultyDirector;
+
+        let mut game_state = GameState {
+            director: DifficultyDirector {
+                projectile_speed_scale: 1.5,
+                ..Default::default()
+            },
+            alien_formation: AlienFormation::new(GAME_WIDTH),
+            ..Default::default()
+        };
+
+        handle_alien_shooting(&mut game_state, 0.1);
+        assert_eq!(game_state.alien_projectiles[0].velocity, 200.0);
+
+        for alien in &mut game_state.alien_formation.aliens {
+            alien.is_alive = false;
+        }
+        check_level_completion(&mut game_state);
+        assert!(game_state.director.decisions.is_empty());
+    }
+
+    // Test that completing a level lets the director log a decision and adjust the tempo
+    #[test]
+    fn test_adaptive_director_on_level_completion() {
+        use crate::game::director::LevelStats;
+        use crate::game::options::GameOptions;
+
+        let mut game_state = GameState {
+            level: 1,
+            options: GameOptions {
+                adaptive_difficulty: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        game_state.director.stats = LevelStats {
+            shots_fired: 20,
+            hits: 18,
+            lives_lost: 0,
+            time: 20.0,
+        };
+
+        check_level_completion(&mut game_state);
+
+        assert_eq!(game_state.director.decisions.len(), 1);
+        assert_eq!(game_state.director.decisions[0].level, 1);
+        assert!(game_state.director.tempo_scale > 1.0);
+        assert!(game_state.alien_formation.move_interval < 0.5 * 0.9);
+    }
+
+    // Test that a movement script drives the formation instead of the sweep
+    #[test]
+    fn test_update_aliens_with_script() {
+        use crate::game::formation_script::{FormationScript, ScriptRunner};
+
+        let mut game_state = GameState {
+            alien_formation: AlienFormation::new(GAME_WIDTH),
+            ..Default::default()
+        };
+        let script = FormationScript::parse("split 100 1; pause 1").unwrap();
+        game_state.alien_formation.script = Some(ScriptRunner::new(script, 11));
+        let left_x = game_state.alien_formation.aliens[0].position.x;
+        let right_x = game_state.alien_formation.aliens[10].position.x;
+
+        update_aliens(&mut game_state, 1.0);
+
+        assert!((game_state.alien_formation.aliens[0].position.x - (left_x - 50.0)).abs() < 0.01);
+        assert!((game_state.alien_formation.aliens[10].position.x - (right_x + 50.0)).abs() < 0.01);
+        assert_eq!(game_state.alien_formation.move_timer, 0.0);
+    }
+
+    // Test that accelerate speeds up the sweep and the sweep step still moves aliens
+    #[test]
+    fn test_update_aliens_script_accelerate() {
+        use crate::game::formation_script::{FormationScript, ScriptRunner};
+
+        let mut game_state = GameState {
+            alien_formation: AlienFormation::new(GAME_WIDTH),
+            ..Default::default()
+        };
+        let script = FormationScript::parse("accelerate 2; sweep").unwrap();
+        game_state.alien_formation.script = Some(ScriptRunner::new(script, 11));
+        let start_x = game_state.alien_formation.aliens[0].position.x;
+
+        update_aliens(&mut game_state, 0.3);
+
+        assert_eq!(game_state.alien_formation.speed, 40.0);
+        assert_eq!(game_state.alien_formation.move_interval, 0.25);
+        assert_eq!(
+            game_state.alien_formation.aliens[0].position.x,
+            start_x + 40.0
+        );
+    }
+
+    // Test that dives start on the level's interval and respect the diver limit
+    #[test]
+    fn test_start_dive() {
+        use crate::game::level::LevelConfig;
+
+        let mut game_state = GameState {
+            alien_formation: AlienFormation::new(GAME_WIDTH),
+            level_configs: vec![LevelConfig {
+                dive_interval: Some(1.0),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        update_dives(&mut game_state, 0.5);
+        assert!(!game_state
+            .alien_formation
+            .aliens
+            .iter()
+            .any(|a| a.is_diving()));
+
+        update_dives(&mut game_state, 0.5);
+        assert_eq!(
+            game_state
+                .alien_formation
+                .aliens
+                .iter()
+                .filter(|a| a.is_diving())
+                .count(),
+            1
+        );
+
+        assert!(start_dive(&mut game_state));
+        assert!(!start_dive(&mut game_state));
+    }
+
+    // Test that a returning diver follows its path, shoots, and rejoins its slot
+    #[test]
+    fn test_dive_returns_to_slot() {
+        let mut game_state = GameState {
+            alien_formation: AlienFormation::new(GAME_WIDTH),
+            ..Default::default()
+        };
+        let slot = game_state.alien_formation.aliens[0].position.clone();
+        game_state.alien_formation.aliens[0].dive =
+            Some(Dive::new(slot.clone(), 500.0, 600.0, DiveOutcome::Return));
+
+        update_dives(&mut game_state, 2.0);
+        assert_ne!(game_state.alien_formation.aliens[0].position, slot);
+        assert!(!game_state.alien_projectiles.is_empty());
+
+        for _ in 0..100 {
+            update_dives(&mut game_state, 0.1);
+        }
+        let alien = &game_state.alien_formation.aliens[0];
+        assert!(!alien.is_diving());
+        assert!(alien.is_alive);
+        assert_eq!(alien.position, slot);
+    }
+
+    // Test that an exiting diver leaves the screen and is removed from the wave
+    #[test]
+    fn test_dive_exits() {
+        let mut game_state = GameState {
+            alien_formation: AlienFormation::new(GAME_WIDTH),
+            ..Default::default()
+        };
+        let slot = game_state.alien_formation.aliens[0].position.clone();
+        game_state.alien_formation.aliens[0].dive =
+            Some(Dive::new(slot, 500.0, 690.0, DiveOutcome::Exit));
+
+        for _ in 0..40 {
+            update_dives(&mut game_state, 0.1);
+        }
+
+        assert!(!game_state.alien_formation.aliens[0].is_alive);
+        assert_eq!(game_state.alien_formation.count_living(), 54);
+        assert_eq!(game_state.alien_formation.aliens_escaped, 1);
+        assert_eq!(game_state.score, 0);
+    }
+
+    // Test that a diving alien crashing into the player costs a life
+    #[test]
+    fn test_diver_collides_with_player() {
+        let mut game_state = GameState {
+            lives: 3,
+            alien_formation: AlienFormation::new(GAME_WIDTH),
+            ..Default::default()
+        };
+        let player_position = game_state.player.position.clone();
+        let alien = &mut game_state.alien_formation.aliens[0];
+        alien.dive = Some(Dive::new(
+            alien.position.clone(),
+            500.0,
+            690.0,
+            DiveOutcome::Return,
+        ));
+        alien.position = Position::new(player_position.x - 20.0, player_position.y);
+
+        check_player_collisions(&mut game_state);
+
+        assert_eq!(game_state.lives, 2);
+        assert!(!game_state.alien_formation.aliens[0].is_alive);
+        assert!(game_state.invincibility_timer > 0.0);
+    }
+
+    // Test that tough aliens survive partial hits and award points once destroyed
+    #[test]
+    fn test_hit_alien_with_armor() {
+        use crate::game::entities::alien::AlienToughness;
+
+        let mut game_state = GameState {
+            alien_formation: AlienFormation::new(GAME_WIDTH),
+            ..Default::default()
+        };
+        game_state.alien_formation.aliens[0].set_toughness(AlienToughness {
+            hit_points: 1,
+            armor: 1,
+        });
+
+        assert!(!hit_alien(&mut game_state, 0));
+        assert!(game_state.alien_formation.aliens[0].is_alive);
+        assert_eq!(game_state.score, 0);
+
+        assert!(hit_alien(&mut game_state, 0));
+        assert!(!game_state.alien_formation.aliens[0].is_alive);
+        assert_eq!(game_state.score, 60);
+        assert!(!hit_alien(&mut game_state, 0));
+        assert!(!hit_alien(&mut game_state, 999));
+    }
+
+    // Test that the next level's formation uses the level's row toughness
+    #[test]
+    fn test_level_completion_applies_toughness() {
+        let mut game_state = GameState {
+            level: 2,
+            level_configs: crate::game::level::default_level_configs(),
+            ..Default::default()
+        };
+
+        check_level_completion(&mut game_state);
+
+        assert_eq!(game_state.level, 3);
+        assert_eq!(game_state.alien_formation.aliens[0].hit_points, 2);
+        assert_eq!(game_state.alien_formation.aliens[11].hit_points, 1);
+    }
+
+    // Test that every fifth level replaces the formation with a boss
+    #[test]
+    fn test_boss_level_spawns_boss() {
+        let mut game_state = GameState {
+            level: 4,
+            ..Default::default()
+        };
+
+        check_level_completion(&mut game_state);
+
+        assert_eq!(game_state.level, 5);
+        assert!(game_state.boss.is_some());
+        assert_eq!(game_state.alien_formation.count_living(), 0);
+
+        check_level_completion(&mut game_state);
+        assert_eq!(game_state.level, 5);
+    }
+
+    // Test that defeating the boss completes the level
+    #[test]
+    fn test_boss_defeat_completes_level() {
+        let mut boss = Boss::new(GAME_WIDTH);
+        for segment in &mut boss.segments {
+            segment.hit_points = 0;
+        }
+        let mut game_state = GameState {
+            level: 5,
+            boss: Some(boss),
+            ..Default::default()
+        };
+
+        check_level_completion(&mut game_state);
+
+        assert_eq!(game_state.level, 6);
+        assert!(game_state.boss.is_none());
+        assert_eq!(game_state.alien_formation.count_living(), 55);
+    }
+
+    // Test that player projectiles damage the boss and the final hit awards the bonus
+    #[test]
+    fn test_boss_collisions() {
+        let mut boss = Boss::new(GAME_WIDTH);
+        for (index, segment) in boss.segments.iter_mut().enumerate() {
+            if index != 8 {
+                segment.hit_points = 0;
+            }
+        }
+        boss.segments[8].hit_points = 2;
+        let core_x = boss.position.x + boss.segments[8].offset.x + 20.0;
+        let core_y = boss.position.y + boss.segments[8].offset.y + 20.0;
+
+        let mut game_state = GameState {
+            boss: Some(boss),
+            player_projectiles: vec![Projectile::new(core_x, core_y, true)],
+            ..Default::default()
+        };
+
+        check_projectile_collisions(&mut game_state);
+        assert!(game_state.player_projectiles.is_empty());
+        assert_eq!(game_state.boss.as_ref().unwrap().hit_points(), 1);
+        assert_eq!(game_state.score, 0);
+
+        game_state
+            .player_projectiles
+            .push(Projectile::new(core_x, core_y, true));
+        check_projectile_collisions(&mut game_state);
+
+        assert!(game_state.boss.as_ref().unwrap().is_defeated());
+        assert_eq!(game_state.score, 300 + BOSS_DEFEAT_POINTS);
+        assert!(game_state.events.contains(&GameEvent::BossDefeated));
+    }
+
+    // Test that the boss fires from its cannons and changes phase as it is damaged
+    #[test]
+    fn test_update_boss() {
+        let mut game_state = GameState {
+            boss: Some(Boss::new(GAME_WIDTH)),
+            ..Default::default()
+        };
+
+        update_boss(&mut game_state, 2.1);
+        assert_eq!(game_state.alien_projectiles.len(), 3);
+
+        game_state.boss.as_mut().unwrap().segments[8].hit_points = 0;
+        update_boss(&mut game_state, 0.01);
+        assert!(game_state.events.contains(&GameEvent::BossPhaseChanged {
+            phase: crate::game::entities::boss::BossPhase::Barrage,
+        }));
+    }
+
+    // Test that reaching the invasion line on Easy costs a life instead of the game
+    #[test]
+    fn test_invasion_on_easy_costs_a_life() {
+        use crate::game::difficulty::Difficulty;
+        use crate::game::options::GameOptions;
+
+        let mut game_state = GameState {
+            lives: 3,
+            options: GameOptions {
+                difficulty: Difficulty::Easy,
+                ..Default::default()
+            },
+            alien_formation: AlienFormation::new(GAME_WIDTH),
+            ..Default::default()
+        };
+        for alien in &mut game_state.alien_formation.aliens {
+            alien.position.y += 400.0;
+        }
+
+        check_game_over_conditions(&mut game_state);
+
+        assert!(!game_state.game_over);
+        assert_eq!(game_state.lives, 2);
+        assert!(game_state.alien_formation.get_lowest_y() < 550.0);
+    }
+
+    // Test that finishing a game records it on the leaderboard with its difficulty
+    #[test]
+    fn test_finish_game_records_difficulty() {
+        use crate::game::difficulty::Difficulty;
+        use crate::game::options::GameOptions;
+
+        let mut game_state = GameState {
+            score: 1200,
+            level: 4,
+            game_over: true,
+            options: GameOptions {
+                difficulty: Difficulty::Hard,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        finish_game(&mut game_state);
+        assert_eq!(game_state.screen, GameScreen::InitialsEntry);
+
+        game_state.initials.type_letter('b');
+        submit_initials(&mut game_state);
+
+        assert_eq!(game_state.screen, GameScreen::HallOfFame);
+        assert_eq!(game_state.hall_of_fame_rank, Some(0));
+        assert_eq!(
+            game_state.leaderboard.entries,
+            vec![LeaderboardEntry {
+                initials: "BAA".to_string(),
+                score: 1200,
+                level: 4,
+                difficulty: Difficulty::Hard,
+            }]
+        );
+    }
+
+    // Test that a score too low for the leaderboard goes straight to game over
+    #[test]
+    fn test_finish_game_without_qualifying_score() {
+        let mut game_state = GameState {
+            game_over: true,
+            ..Default::default()
+        };
+
+        finish_game(&mut game_state);
+
+        assert_eq!(game_state.screen, GameScreen::GameOver);
+        assert!(game_state.leaderboard.entries.is_empty());
     }
 
     // Test that alien destruction increases score
@@ -489,6 +1831,194 @@ mod tests {
         assert!(game_state.score > initial_score);
     }
 
+    // Test that crossing an extra life threshold awards a life and raises an event
+    #[test]
+    fn test_add_score_awards_extra_life() {
+        use crate::game::scoring::ExtraLifeConfig;
+
+        let mut game_state = GameState {
+            score: 1480,
+            lives: 2,
+            extra_life: ExtraLifeConfig::standard(),
+            ..Default::default()
+        };
+
+        add_score(&mut game_state, 30);
+
+        assert_eq!(game_state.lives, 3);
+        assert!(game_state.extra_life_flash_timer > 0.0);
+        assert_eq!(game_state.events, vec![GameEvent::ExtraLife { lives: 3 }]);
+
+        add_score(&mut game_state, 30);
+        assert_eq!(game_state.lives, 3);
+    }
+
+    // Test that extra lives are not awarded above the cap
+    #[test]
+    fn test_add_score_respects_max_lives() {
+        use crate::game::scoring::ExtraLifeConfig;
+
+        let mut game_state = GameState {
+            lives: 5,
+            extra_life: ExtraLifeConfig {
+                thresholds: vec![],
+                every: Some(100),
+                max_lives: 6,
+            },
+            ..Default::default()
+        };
+
+        add_score(&mut game_state, 500);
+
+        assert_eq!(game_state.lives, 6);
+        assert_eq!(game_state.events.len(), 1);
+    }
+
+    // Test that mystery ship points count towards extra lives
+    #[test]
+    fn test_mystery_ship_points_award_extra_life() {
+        use crate::game::scoring::ExtraLifeConfig;
+
+        let mut ship = MysteryShip::new();
+        ship.active = true;
+        ship.points = 300;
+        ship.position.x = 100.0;
+
+        let mut game_state = GameState {
+            score: 1400,
+            lives: 1,
+            extra_life: ExtraLifeConfig::standard(),
+            mystery_ship: Some(ship),
+            player_projectiles: vec![Projectile::new(110.0, 15.0, true)],
+            ..Default::default()
+        };
+
+        check_projectile_collisions(&mut game_state);
+
+        assert_eq!(game_state.score, 1700);
+        assert_eq!(game_state.lives, 2);
+    }
+
+    // Test that consecutive hits multiply the points when combo scoring is on
+    #[test]
+    fn test_combo_scoring_multiplies_points() {
+        use crate::game::entities::alien::{Alien, AlienType};
+        use crate::game::options::GameOptions;
+        use crate::game::scoring::ComboTracker;
+
+        let mut game_state = GameState {
+            options: GameOptions {
+                combo_scoring: true,
+                ..Default::default()
+            },
+            combo: ComboTracker { hits: 4 },
+            ..Default::default()
+        };
+        let alien = Alien::new(100.0, 100.0, AlienType::Large);
+        game_state.player_projectiles.push(Projectile::new(
+            alien.position.x + 5.0,
+            alien.position.y + 5.0,
+            true,
+        ));
+        game_state.alien_formation.aliens.push(alien);
+
+        check_projectile_collisions(&mut game_state);
+
+        assert_eq!(game_state.combo.hits, 5);
+        assert_eq!(game_state.score, AlienType::Large.points() * 2);
+        assert_eq!(
+            game_state.score_popups[0].points,
+            AlienType::Large.points() * 2
+        );
+    }
+
+    // Test that points are not multiplied when combo scoring is off
+    #[test]
+    fn test_combo_scoring_disabled() {
+        use crate::game::scoring::ComboTracker;
+
+        let mut game_state = GameState {
+            combo: ComboTracker { hits: 50 },
+            alien_formation: AlienFormation::new(GAME_WIDTH),
+            ..Default::default()
+        };
+
+        destroy_alien(&mut game_state, 0);
+
+        assert_eq!(game_state.score, 30);
+    }
+
+    // Test that a player projectile leaving the screen breaks the combo
+    #[test]
+    fn test_miss_resets_combo() {
+        use crate::game::scoring::ComboTracker;
+
+        let mut game_state = GameState {
+            combo: ComboTracker { hits: 7 },
+            player_projectiles: vec![Projectile::new(100.0, -14.0, true)],
+            ..Default::default()
+        };
+
+        update_projectiles(&mut game_state, 0.1);
+
+        assert!(game_state.player_projectiles.is_empty());
+        assert_eq!(game_state.combo.hits, 0);
+    }
+
+    // Test that being hit breaks the combo
+    #[test]
+    fn test_player_hit_resets_combo() {
+        use crate::game::scoring::ComboTracker;
+
+        let player = Player::default();
+        let mut game_state = GameState {
+            lives: 3,
+            combo: ComboTracker { hits: 7 },
+            alien_projectiles: vec![Projectile::new(
+                player.position.x,
+                player.position.y + 5.0,
+                false,
+            )],
+            player,
+            ..Default::default()
+        };
+
+        check_projectile_collisions(&mut game_state);
+
+        assert_eq!(game_state.lives, 2);
+        assert_eq!(game_state.combo.hits, 0);
+    }
+
+    // Test that the extra life flash fades out
+    #[test]
+    fn test_update_hud() {
+        let mut game_state = GameState {
+            extra_life_flash_timer: 0.5,
+            ..Default::default()
+        };
+
+        update_hud(&mut game_state, 0.3);
+        assert!(game_state.extra_life_flash_timer > 0.0);
+
+        update_hud(&mut game_state, 0.3);
+        assert_eq!(game_state.extra_life_flash_timer, 0.0);
+    }
+
+    // Test that score popups float up and disappear
+    #[test]
+    fn test_update_hud_score_popups() {
+        let mut game_state = GameState {
+            score_popups: vec![ScorePopup::new(100.0, 100.0, 30)],
+            ..Default::default()
+        };
+
+        update_hud(&mut game_state, 0.1);
+        assert!(game_state.score_popups[0].position.y < 100.0);
+
+        update_hud(&mut game_state, 1.0);
+        assert!(game_state.score_popups.is_empty());
+    }
+
     // Test that level completion creates a new alien formation
     #[test]
     fn test_level_completion() {
@@ -505,5 +2035,138 @@ mod tests {
 
         assert_eq!(game_state.level, initial_level + 1);
         assert!(game_state.alien_formation.count_living() > 0);
+        assert!(game_state.events.contains(&GameEvent::LevelCleared {
+            level: initial_level
+        }));
+    }
+
+    // Test that clearing a level starts a transition that slides the next wave in
+    #[test]
+    fn test_level_transition() {
+        let mut game_state = GameState {
+            level: 1,
+            score: 700,
+            wave_start_score: 200,
+            ..Default::default()
+        };
+        game_state.director.stats.shots_fired = 10;
+        game_state.director.stats.hits = 4;
+        game_state
+            .player_projectiles
+            .push(Projectile::player(&game_state.player));
+        game_state.mystery_ship = Some(MysteryShip::new());
+        for alien in &mut game_state.alien_formation.aliens {
+            alien.is_alive = false;
+        }
+
+        check_level_completion(&mut game_state);
+
+        let transition = game_state.transition.clone().unwrap();
+        assert_eq!(transition.cleared_level, 1);
+        assert_eq!(transition.points, 500);
+        assert_eq!(transition.stats.hits, 4);
+        assert_eq!(game_state.wave_start_score, 700);
+        assert!(game_state.player_projectiles.is_empty());
+        assert!(game_state.mystery_ship.is_none());
+        assert!(game_state
+            .alien_formation
+            .aliens
+            .iter()
+            .all(|alien| alien.position.y + alien.height <= 0.0));
+
+        let expected = level_config(&game_state.level_configs, 2).build_formation();
+        while game_state.transition.is_some() {
+            update_level_transition(&mut game_state, 0.1);
+        }
+        for (alien, placed) in game_state
+            .alien_formation
+            .aliens
+            .iter()
+            .zip(&expected.aliens)
+        {
+            assert!((alien.position.y - placed.position.y).abs() < 0.01);
+        }
+    }
+
+    // Test that clearing a level warps the starfield, unless reduced motion is on
+    #[test]
+    fn test_update_starfield() {
+        let mut game_state = GameState::default();
+        game_state.events.push(GameEvent::LevelCleared { level: 1 });
+
+        update_starfield(&mut game_state, 0.1);
+        assert!(game_state.starfield.warp > 0.0);
+        assert_eq!(game_state.starfield.time, 0.1);
+
+        let mut still = GameState::default();
+        still.settings.reduced_motion = true;
+        still.events.push(GameEvent::LevelCleared { level: 1 });
+        let starfield = still.starfield.clone();
+
+        update_starfield(&mut still, 0.1);
+        assert_eq!(still.starfield, starfield);
+    }
+
+    // Test that each shield policy is applied when a level is completed
+    #[test]
+    fn test_level_completion_applies_shield_policy() {
+        use crate::game::level::LevelConfig;
+
+        let policies = [
+            ShieldPolicy::Keep,
+            ShieldPolicy::Regenerate,
+            ShieldPolicy::Repair(100),
+            ShieldPolicy::Remove,
+        ];
+
+        for policy in policies {
+            let mut game_state = GameState {
+                level: 1,
+                shields: default_shields(),
+                level_configs: vec![
+                    LevelConfig::default(),
+                    LevelConfig {
+                        shield_policy: policy,
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            };
+            for row in game_state.shields[0].segments.iter_mut() {
+                row[0] = false;
+            }
+            let damaged = game_state.shields[0].destroyed_segment_count();
+
+            check_level_completion(&mut game_state);
+
+            match policy {
+                ShieldPolicy::Keep => {
+                    assert_eq!(game_state.shields[0].destroyed_segment_count(), damaged)
+                }
+                ShieldPolicy::Regenerate | ShieldPolicy::Repair(_) => {
+                    assert_eq!(game_state.shields[0].destroyed_segment_count(), 0)
+                }
+                ShieldPolicy::Remove => assert!(game_state.shields.is_empty()),
+            }
+        }
+    }
+
+    // Test that regenerating brings back shields removed on an earlier level
+    #[test]
+    fn test_regenerate_after_remove() {
+        use crate::game::level::LevelConfig;
+
+        let mut game_state = GameState {
+            level: 2,
+            level_configs: vec![LevelConfig {
+                shield_policy: ShieldPolicy::Regenerate,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        apply_shield_policy(&mut game_state);
+
+        assert_eq!(game_state.shields.len(), 6);
     }
 }
@@ -1,3 +1,16 @@
+pub mod difficulty;
+pub mod director;
+pub mod editor;
 pub mod entities;
+pub mod events;
+pub mod formation_script;
+pub mod leaderboard;
+pub mod level;
+pub mod level_file;
 pub mod logic;
+pub mod options;
+pub mod scoring;
+pub mod settings;
+pub mod starfield;
 pub mod state;
+pub mod transition;
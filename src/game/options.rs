@@ -0,0 +1,14 @@
+use crate::game::difficulty::Difficulty;
+use crate::game::entities::mystery_ship::MysteryShipScoring;
+
+/// Player-selectable gameplay options
+/// Options are chosen on the start screen and carried over from one game to the next.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct GameOptions {
+    pub combo_scoring: bool,
+    pub mystery_ship_scoring: MysteryShipScoring,
+    pub difficulty: Difficulty,
+    pub adaptive_difficulty: bool,
+    /// The ship follows the mouse or finger and clicking fires
+    pub pointer_control: bool,
+}
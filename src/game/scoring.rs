@@ -0,0 +1,169 @@
+use crate::utils::position::Position;
+
+const COMBO_HITS_PER_STEP: u32 = 5;
+const MAX_COMBO_MULTIPLIER: i32 = 5;
+/// Seconds a score popup stays on screen, fading out as it rises
+pub const SCORE_POPUP_DURATION: f64 = 0.8;
+
+/// Describes when the player earns extra lives
+/// Lives can be awarded once at fixed scores (e.g. 1500) and/or repeatedly
+/// every time the score passes another multiple of `every` (e.g. 10,000).
+/// No lives are awarded above `max_lives`.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ExtraLifeConfig {
+    pub thresholds: Vec<i32>,
+    pub every: Option<i32>,
+    pub max_lives: i32,
+}
+
+impl ExtraLifeConfig {
+    /// Creates the extra life rules used by the standard game
+    /// # Returns -> A config awarding a single extra life at 1500 points, up to 5 lives
+    pub fn standard() -> Self {
+        Self {
+            thresholds: vec![1500],
+            every: None,
+            max_lives: 5,
+        }
+    }
+
+    /// Counts how many extra lives are earned when the score goes up
+    /// # Arguments
+    /// * `old_score` - The score before the points were added
+    /// * `new_score` - The score after the points were added
+    /// # Returns -> The number of thresholds crossed between the two scores
+    pub fn lives_earned(&self, old_score: i32, new_score: i32) -> i32 {
+        if new_score <= old_score {
+            return 0;
+        }
+
+        let fixed = self
+            .thresholds
+            .iter()
+            .filter(|&&threshold| old_score < threshold && threshold <= new_score)
+            .count() as i32;
+
+        let repeating = match self.every {
+            Some(every) if every > 0 => new_score / every - old_score / every,
+            _ => 0,
+        };
+
+        fixed + repeating
+    }
+}
+
+/// Tracks consecutive hits for the optional combo scoring system
+/// Every `COMBO_HITS_PER_STEP` hits in a row raise the score multiplier by one,
+/// up to `MAX_COMBO_MULTIPLIER`. A miss or losing a life resets the streak.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ComboTracker {
+    pub hits: u32,
+}
+
+impl ComboTracker {
+    /// Records a successful hit, extending the streak
+    pub fn register_hit(&mut self) {
+        self.hits += 1;
+    }
+
+    /// Ends the current streak
+    pub fn reset(&mut self) {
+        self.hits = 0;
+    }
+
+    /// Calculates the score multiplier for the current streak
+    /// # Returns -> The multiplier, starting at 1
+    pub fn multiplier(&self) -> i32 {
+        (1 + (self.hits / COMBO_HITS_PER_STEP) as i32).min(MAX_COMBO_MULTIPLIER)
+    }
+}
+
+/// A short-lived label showing the points awarded for a hit
+#[derive(Clone, PartialEq, Debug)]
+pub struct ScorePopup {
+    pub position: Position,
+    pub points: i32,
+    pub timer: f64,
+}
+
+impl ScorePopup {
+    /// Creates a popup at the given position
+    /// # Arguments
+    /// * `x` - The x-coordinate of the popup
+    /// * `y` - The y-coordinate of the popup
+    /// * `points` - The points to display
+    /// # Returns -> A new ScorePopup that will fade after a short time
+    pub fn new(x: f32, y: f32, points: i32) -> Self {
+        Self {
+            position: Position::new(x, y),
+            points,
+            timer: SCORE_POPUP_DURATION,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test that the combo multiplier grows with the streak and is capped
+    #[test]
+    fn test_combo_multiplier() {
+        let mut combo = ComboTracker::default();
+        assert_eq!(combo.multiplier(), 1);
+
+        for _ in 0..COMBO_HITS_PER_STEP {
+            combo.register_hit();
+        }
+        assert_eq!(combo.multiplier(), 2);
+
+        for _ in 0..100 {
+            combo.register_hit();
+        }
+        assert_eq!(combo.multiplier(), MAX_COMBO_MULTIPLIER);
+
+        combo.reset();
+        assert_eq!(combo.hits, 0);
+        assert_eq!(combo.multiplier(), 1);
+    }
+
+    // Test that the default config never awards lives
+    #[test]
+    fn test_default_awards_nothing() {
+        let config = ExtraLifeConfig::default();
+        assert_eq!(config.lives_earned(0, 100_000), 0);
+    }
+
+    // Test that a fixed threshold is only awarded once
+    #[test]
+    fn test_fixed_threshold() {
+        let config = ExtraLifeConfig::standard();
+        assert_eq!(config.lives_earned(1400, 1490), 0);
+        assert_eq!(config.lives_earned(1490, 1500), 1);
+        assert_eq!(config.lives_earned(1500, 3000), 0);
+    }
+
+    // Test that a repeating threshold is awarded every interval
+    #[test]
+    fn test_repeating_threshold() {
+        let config = ExtraLifeConfig {
+            thresholds: vec![],
+            every: Some(10_000),
+            max_lives: 9,
+        };
+        assert_eq!(config.lives_earned(9_990, 10_010), 1);
+        assert_eq!(config.lives_earned(10_010, 19_990), 0);
+        assert_eq!(config.lives_earned(19_990, 39_990), 2);
+    }
+
+    // Test that fixed and repeating thresholds are combined
+    #[test]
+    fn test_combined_thresholds() {
+        let config = ExtraLifeConfig {
+            thresholds: vec![1500],
+            every: Some(10_000),
+            max_lives: 9,
+        };
+        assert_eq!(config.lives_earned(0, 10_000), 2);
+    }
+}
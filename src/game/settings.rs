@@ -0,0 +1,448 @@
+use crate::game::difficulty::Difficulty;
+use crate::game::state::GameScreen;
+use crate::input::bindings::Action;
+use crate::utils::i18n::{self, Language};
+use crate::utils::storage;
+use serde::{Deserialize, Serialize};
+
+/// The name the settings are saved under
+pub const SETTINGS_FILE: &str = "settings.toml";
+/// The loudest volume setting
+pub const MAX_VOLUME: u8 = 10;
+
+/// The look of the game: sprite colors and art, and the fonts and colors of the screens
+/// Each theme is a CSS class on the game container; see `assets/styling/main.css`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Theme {
+    /// The game's original colorful look
+    #[default]
+    Standard,
+    /// White pixel-art sprites and a typewriter font, like the 1978 cabinet
+    Classic,
+    /// Glowing saturated colors and sleek sprites
+    Neon,
+}
+
+impl Theme {
+    /// Every theme, in the order the Settings screen cycles through them
+    pub const ALL: [Theme; 3] = [Theme::Standard, Theme::Classic, Theme::Neon];
+
+    /// Returns -> the CSS class that applies the theme
+    pub fn css_class(&self) -> &'static str {
+        match self {
+            Theme::Standard => "theme-standard",
+            Theme::Classic => "theme-classic",
+            Theme::Neon => "theme-neon",
+        }
+    }
+
+    /// Returns -> the name shown on the Settings screen, in the given language
+    pub fn label(&self, language: Language) -> String {
+        let key = match self {
+            Theme::Standard => "theme.standard",
+            Theme::Classic => "theme.classic",
+            Theme::Neon => "theme.neon",
+        };
+        i18n::text(language, key)
+    }
+}
+
+/// Colors used for aliens, projectiles, shields and the player
+/// `Theme` keeps the colors of the current theme. The alternatives keep every
+/// kind of sprite distinguishable for players with common color-vision
+/// deficiencies, or maximize contrast.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Palette {
+    /// The colors of the current theme
+    #[default]
+    #[serde(alias = "classic")]
+    Theme,
+    /// Safe for protanopia and deuteranopia (red-green color blindness)
+    RedGreen,
+    /// Safe for tritanopia (blue-yellow color blindness)
+    BlueYellow,
+    /// Bright, saturated colors on pure black
+    HighContrast,
+}
+
+impl Palette {
+    /// Every palette, in the order the Settings screen cycles through them
+    pub const ALL: [Palette; 4] = [
+        Palette::Theme,
+        Palette::RedGreen,
+        Palette::BlueYellow,
+        Palette::HighContrast,
+    ];
+
+    /// Returns -> the CSS class that selects the palette's colors
+    pub fn css_class(&self) -> &'static str {
+        match self {
+            Palette::Theme => "palette-theme",
+            Palette::RedGreen => "palette-red-green",
+            Palette::BlueYellow => "palette-blue-yellow",
+            Palette::HighContrast => "palette-high-contrast",
+        }
+    }
+
+    /// Returns -> the name shown on the Settings screen, in the given language
+    pub fn label(&self, language: Language) -> String {
+        let key = match self {
+            Palette::Theme => "palette.theme",
+            Palette::RedGreen => "palette.red_green",
+            Palette::BlueYellow => "palette.blue_yellow",
+            Palette::HighContrast => "palette.high_contrast",
+        };
+        i18n::text(language, key)
+    }
+}
+
+/// Player preferences that are kept between sessions
+/// Settings are stored as TOML; any value missing from the file keeps its default.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The difficulty selected when the game starts
+    pub difficulty: Difficulty,
+    /// Sound volume from 0 (muted) to MAX_VOLUME
+    pub volume: u8,
+    /// Show the points floating up from destroyed aliens
+    pub score_popups: bool,
+    /// Show the starfield behind the playfield
+    pub starfield: bool,
+    /// The look of sprites and screens
+    pub theme: Theme,
+    /// Colors used for the sprites, overriding the theme's
+    pub palette: Palette,
+    /// Turn off flicker, blinking and other animations
+    pub reduced_motion: bool,
+    /// Show the frames-per-second counter
+    pub show_fps: bool,
+    /// Code of the language the game's text is shown in
+    pub language: String,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            difficulty: Difficulty::default(),
+            volume: 7,
+            score_popups: true,
+            starfield: true,
+            theme: Theme::default(),
+            palette: Palette::default(),
+            reduced_motion: false,
+            show_fps: false,
+            language: Language::default().code().to_string(),
+        }
+    }
+}
+
+/// A line on the Settings screen
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SettingsRow {
+    Difficulty,
+    Controls,
+    Volume,
+    ScorePopups,
+    Starfield,
+    Theme,
+    Palette,
+    ReducedMotion,
+    ShowFps,
+    Language,
+}
+
+impl SettingsRow {
+    /// Every row, in the order the Settings screen lists them
+    pub const ALL: [SettingsRow; 10] = [
+        SettingsRow::Difficulty,
+        SettingsRow::Controls,
+        SettingsRow::Volume,
+        SettingsRow::ScorePopups,
+        SettingsRow::Starfield,
+        SettingsRow::Theme,
+        SettingsRow::Palette,
+        SettingsRow::ReducedMotion,
+        SettingsRow::ShowFps,
+        SettingsRow::Language,
+    ];
+
+    /// Returns -> the label shown for the row, in the given language
+    pub fn label(&self, language: Language) -> String {
+        let key = match self {
+            SettingsRow::Difficulty => "settings.difficulty",
+            SettingsRow::Controls => "settings.controls",
+            SettingsRow::Volume => "settings.volume",
+            SettingsRow::ScorePopups => "settings.score_popups",
+            SettingsRow::Starfield => "settings.starfield",
+            SettingsRow::Theme => "settings.theme",
+            SettingsRow::Palette => "settings.palette",
+            SettingsRow::ReducedMotion => "settings.reduced_motion",
+            SettingsRow::ShowFps => "settings.show_fps",
+            SettingsRow::Language => "settings.language",
+        };
+        i18n::text(language, key)
+    }
+}
+
+impl Settings {
+    /// Changes the value on a row of the Settings screen
+    /// Toggles flip whichever direction is pressed; lists wrap around.
+    /// # Arguments
+    /// * `row` - The row to change
+    /// * `delta` - 1 for the next value, -1 for the previous one
+    pub fn adjust(&mut self, row: SettingsRow, delta: i32) {
+        match row {
+            SettingsRow::Difficulty => {
+                self.difficulty = if delta < 0 {
+                    self.difficulty.previous()
+                } else {
+                    self.difficulty.next()
+                };
+            }
+            SettingsRow::Controls => {}
+            SettingsRow::Volume => {
+                self.volume = (self.volume as i32 + delta).clamp(0, MAX_VOLUME as i32) as u8;
+            }
+            SettingsRow::ScorePopups => self.score_popups = !self.score_popups,
+            SettingsRow::Starfield => self.starfield = !self.starfield,
+            SettingsRow::Theme => self.theme = cycle(&Theme::ALL, self.theme, delta),
+            SettingsRow::Palette => self.palette = cycle(&Palette::ALL, self.palette, delta),
+            SettingsRow::ReducedMotion => self.reduced_motion = !self.reduced_motion,
+            SettingsRow::ShowFps => self.show_fps = !self.show_fps,
+            SettingsRow::Language => {
+                self.language = cycle(&Language::ALL, self.language(), delta)
+                    .code()
+                    .to_string();
+            }
+        }
+    }
+
+    /// Returns -> the language the game's text is shown in
+    pub fn language(&self) -> Language {
+        Language::from_code(&self.language).unwrap_or_default()
+    }
+
+    /// Returns -> the value shown on a row of the Settings screen, in the chosen language
+    pub fn value(&self, row: SettingsRow) -> String {
+        let language = self.language();
+        let on_off = |on: bool| i18n::on_off(language, on);
+
+        match row {
+            SettingsRow::Difficulty => self.difficulty.label(language),
+            SettingsRow::Controls => i18n::text(language, "settings.edit"),
+            SettingsRow::Volume => format!("{}/{}", self.volume, MAX_VOLUME),
+            SettingsRow::ScorePopups => on_off(self.score_popups),
+            SettingsRow::Starfield => on_off(self.starfield),
+            SettingsRow::Theme => self.theme.label(language),
+            SettingsRow::Palette => self.palette.label(language),
+            SettingsRow::ReducedMotion => on_off(self.reduced_motion),
+            SettingsRow::ShowFps => on_off(self.show_fps),
+            SettingsRow::Language => language.name().to_string(),
+        }
+    }
+
+    /// Reads settings from their TOML text
+    /// # Arguments
+    /// * `source` - The contents of a settings file
+    /// # Returns -> The settings, or the TOML error message
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let mut settings: Self = toml::from_str(source).map_err(|e| e.message().to_string())?;
+        settings.volume = settings.volume.min(MAX_VOLUME);
+        settings.language = settings.language().code().to_string();
+        Ok(settings)
+    }
+
+    /// Returns -> the settings as TOML text
+    pub fn to_toml(&self) -> String {
+        toml::to_string_pretty(self).expect("settings only contain TOML-compatible values")
+    }
+
+    /// Loads the saved settings, falling back to the defaults if there are none
+    /// or the file cannot be read
+    pub fn load() -> Self {
+        storage::load(SETTINGS_FILE)
+            .ok()
+            .and_then(|source| Self::parse(&source).ok())
+            .unwrap_or_default()
+    }
+
+    /// Saves the settings so they are applied the next time the game starts
+    /// # Returns -> `Ok(())` on success, or the I/O error that occurred
+    pub fn save(&self) -> std::io::Result<()> {
+        storage::save(SETTINGS_FILE, &self.to_toml())
+    }
+}
+
+/// Steps through a list of choices, wrapping around at either end
+/// # Arguments
+/// * `all` - Every choice, in order
+/// * `current` - The current choice
+/// * `delta` - 1 for the next choice, -1 for the previous one
+/// # Returns -> The new choice
+fn cycle<T: Copy + PartialEq>(all: &[T], current: T, delta: i32) -> T {
+    let index = all.iter().position(|c| *c == current).unwrap_or(0) as i32;
+    all[(index + delta).rem_euclid(all.len() as i32) as usize]
+}
+
+/// State of the Settings screen
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct SettingsMenu {
+    /// Index into `SettingsRow::ALL` of the highlighted row
+    pub selected: usize,
+    /// The screen to go back to: the start screen or the paused game
+    pub return_to: GameScreen,
+    pub message: Option<String>,
+}
+
+impl SettingsMenu {
+    /// Returns -> the highlighted row
+    pub fn row(&self) -> SettingsRow {
+        SettingsRow::ALL[self.selected]
+    }
+
+    /// Moves the highlight, wrapping around at either end
+    /// # Arguments
+    /// * `delta` - Rows to move down (negative for up)
+    pub fn move_selection(&mut self, delta: i32) {
+        let count = SettingsRow::ALL.len() as i32;
+        self.selected = (self.selected as i32 + delta).rem_euclid(count) as usize;
+    }
+}
+
+/// State of the Controls screen
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ControlsMenu {
+    /// Index into `Action::ALL` of the highlighted action
+    pub selected: usize,
+    /// Whether the next key pressed will be bound to the highlighted action
+    pub capturing: bool,
+    /// The screen to go back to: the start screen or the Settings screen
+    pub return_to: GameScreen,
+    pub message: Option<String>,
+}
+
+impl ControlsMenu {
+    /// Returns -> the highlighted action
+    pub fn action(&self) -> Action {
+        Action::ALL[self.selected]
+    }
+
+    /// Moves the highlight, wrapping around at either end
+    /// # Arguments
+    /// * `delta` - Rows to move down (negative for up)
+    pub fn move_selection(&mut self, delta: i32) {
+        let count = Action::ALL.len() as i32;
+        self.selected = (self.selected as i32 + delta).rem_euclid(count) as usize;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test that every row can be changed and stays within its range
+    #[test]
+    fn test_adjust() {
+        let mut settings = Settings::default();
+
+        settings.adjust(SettingsRow::Difficulty, 1);
+        assert_eq!(settings.difficulty, Difficulty::Hard);
+        settings.adjust(SettingsRow::Difficulty, -1);
+        assert_eq!(settings.difficulty, Difficulty::Normal);
+
+        for _ in 0..20 {
+            settings.adjust(SettingsRow::Volume, 1);
+        }
+        assert_eq!(settings.volume, MAX_VOLUME);
+
+        settings.adjust(SettingsRow::ShowFps, 1);
+        assert!(settings.show_fps);
+        assert_eq!(settings.value(SettingsRow::ShowFps), "ON");
+
+        settings.adjust(SettingsRow::Theme, 1);
+        assert_eq!(settings.theme, Theme::Classic);
+        settings.adjust(SettingsRow::Theme, 2);
+        assert_eq!(settings.theme, Theme::Standard);
+
+        settings.adjust(SettingsRow::Palette, -1);
+        assert_eq!(settings.palette, Palette::HighContrast);
+        settings.adjust(SettingsRow::Palette, 1);
+        assert_eq!(settings.palette, Palette::Theme);
+
+        settings.adjust(SettingsRow::Language, -1);
+        assert_eq!(settings.language, "es");
+        assert_eq!(settings.value(SettingsRow::ShowFps), "SÍ");
+        settings.adjust(SettingsRow::Language, 1);
+        assert_eq!(settings.language(), Language::English);
+    }
+
+    // Test that settings survive a round trip through TOML
+    #[test]
+    fn test_round_trip() {
+        let settings = Settings {
+            difficulty: Difficulty::Arcade,
+            volume: 3,
+            starfield: false,
+            theme: Theme::Neon,
+            palette: Palette::RedGreen,
+            reduced_motion: true,
+            ..Default::default()
+        };
+
+        assert_eq!(Settings::parse(&settings.to_toml()).unwrap(), settings);
+    }
+
+    // Test that partial or out-of-range files are read with sensible values
+    #[test]
+    fn test_parse_partial_file() {
+        let settings = Settings::parse("volume = 99\nlanguage = \"xx\"").unwrap();
+
+        assert_eq!(settings.volume, MAX_VOLUME);
+        assert_eq!(settings.language, "en");
+        assert!(settings.score_popups);
+        assert!(Settings::parse("volume = \"loud\"").is_err());
+
+        let older = Settings::parse("palette = \"classic\"").unwrap();
+        assert_eq!(older.palette, Palette::Theme);
+    }
+
+    // Test that every accessible palette gives each kind of sprite its own color
+    #[test]
+    fn test_palette_colors_are_distinct() {
+        let css = include_str!("../../assets/styling/main.css");
+
+        for palette in &Palette::ALL[1..] {
+            let start = css
+                .find(&format!(".{} {{", palette.css_class()))
+                .expect("every palette has a CSS block");
+            let block = &css[start..start + css[start..].find('}').unwrap()];
+            let colors: Vec<&str> = block
+                .lines()
+                .filter(|line| line.trim_start().starts_with("--"))
+                .map(|line| line.split(':').nth(1).unwrap().trim())
+                .collect();
+
+            assert_eq!(colors.len(), 11, "{palette:?}");
+            for (i, color) in colors.iter().enumerate() {
+                assert!(
+                    !colors[i + 1..].contains(color),
+                    "{palette:?} reuses {color}"
+                );
+            }
+        }
+    }
+
+    // Test that the Controls screen selection wraps around
+    #[test]
+    fn test_controls_menu_selection() {
+        let mut menu = ControlsMenu::default();
+
+        menu.move_selection(-1);
+        assert_eq!(menu.action(), Action::Back);
+        menu.move_selection(1);
+        assert_eq!(menu.action(), Action::MoveLeft);
+    }
+}
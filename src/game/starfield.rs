@@ -0,0 +1,283 @@
+use rand::Rng;
+
+/// How many stars each layer has, from the farthest layer to the nearest
+const LAYER_STARS: [usize; 3] = [60, 35, 20];
+/// How fast each layer scrolls down, in percent of the playfield height per second
+const LAYER_SPEEDS: [f32; 3] = [1.0, 2.5, 5.0];
+/// Share of each layer's stars that are shown while not warping
+const BASE_DENSITY: f32 = 0.6;
+/// How many times faster the stars scroll at full warp
+const WARP_SPEEDUP: f32 = 12.0;
+/// Seconds for a warp to die down completely
+const WARP_DURATION: f32 = 2.0;
+/// How fast stars twinkle, in radians per second
+const TWINKLE_SPEED: f64 = 3.0;
+/// Shortest and longest wait between two shooting stars, in seconds
+const SHOOTING_STAR_INTERVAL: (f32, f32) = (4.0, 10.0);
+/// Seconds a shooting star stays on screen
+const SHOOTING_STAR_LIFE: f32 = 0.8;
+/// How far a shooting star travels, in percent of the playfield per second
+const SHOOTING_STAR_VELOCITY: (f32, f32) = (60.0, 30.0);
+
+/// Represents a single star in the background starfield
+/// Positions are percentages of the playfield's width and height.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Star {
+    pub x: f32,
+    pub y: f32,
+    pub opacity: f32,
+    /// Where in its twinkle the star starts, so stars do not twinkle in step
+    pub twinkle_phase: f32,
+}
+
+impl Star {
+    /// Returns -> the star's opacity at a point in time, twinkling a little below its own opacity
+    /// # Arguments
+    /// * `time` - The starfield's clock, in seconds
+    pub fn brightness(&self, time: f64) -> f32 {
+        let wave = ((time * TWINKLE_SPEED) as f32 + self.twinkle_phase).sin();
+        self.opacity * (0.75 + 0.25 * wave)
+    }
+}
+
+/// Generates a collection of randomly positioned stars
+/// # Arguments
+/// * `count` - The number of stars to generate
+/// # Returns -> A vector containing the specified number of randomly positioned stars
+pub fn generate_stars(count: usize) -> Vec<Star> {
+    let mut rng = rand::thread_rng();
+    let mut stars = Vec::with_capacity(count);
+    for _ in 0..count {
+        stars.push(Star {
+            x: rng.gen::<f32>() * 100.0,
+            y: rng.gen::<f32>() * 100.0,
+            opacity: rng.gen::<f32>() * 0.8 + 0.2,
+            twinkle_phase: rng.gen::<f32>() * std::f32::consts::TAU,
+        });
+    }
+    stars
+}
+
+/// One layer of stars, all scrolling at the same speed
+#[derive(Clone, PartialEq, Debug)]
+pub struct StarLayer {
+    /// Scrolling speed in percent of the playfield height per second
+    pub speed: f32,
+    pub stars: Vec<Star>,
+}
+
+impl StarLayer {
+    /// Returns -> the stars shown at a density, the first ones of the layer
+    /// # Arguments
+    /// * `density` - The share of stars to show, from 0.0 to 1.0
+    pub fn visible(&self, density: f32) -> &[Star] {
+        let count = (self.stars.len() as f32 * density.clamp(0.0, 1.0)).round() as usize;
+        &self.stars[..count]
+    }
+}
+
+/// A streak crossing the sky diagonally, fading out as it goes
+#[derive(Clone, PartialEq, Debug)]
+pub struct ShootingStar {
+    pub x: f32,
+    pub y: f32,
+    /// Seconds left before it disappears
+    pub timer: f32,
+}
+
+impl ShootingStar {
+    /// Returns -> the opacity of the streak, fading from 1.0 to 0.0 over its life
+    pub fn opacity(&self) -> f32 {
+        (self.timer / SHOOTING_STAR_LIFE).clamp(0.0, 1.0)
+    }
+}
+
+/// Parallax starfield behind the playfield
+/// It only moves when `update` is called from the game loop, so it stops when
+/// the game is paused. Clearing a level warps it: the stars speed up and fill
+/// in, then settle back over `WARP_DURATION` seconds.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Starfield {
+    /// Layers from the farthest (slowest) to the nearest (fastest)
+    pub layers: Vec<StarLayer>,
+    pub shooting_stars: Vec<ShootingStar>,
+    /// Seconds the starfield has been running, used for twinkling
+    pub time: f64,
+    /// Strength of the warp effect, 1.0 just after a level is cleared and 0.0 at rest
+    pub warp: f32,
+    /// Seconds until the next shooting star
+    pub shooting_star_timer: f32,
+}
+
+/// Returns a starfield with freshly generated layers
+impl Default for Starfield {
+    fn default() -> Self {
+        Self {
+            layers: LAYER_STARS
+                .iter()
+                .zip(LAYER_SPEEDS)
+                .map(|(&count, speed)| StarLayer {
+                    speed,
+                    stars: generate_stars(count),
+                })
+                .collect(),
+            shooting_stars: Vec::new(),
+            time: 0.0,
+            warp: 0.0,
+            shooting_star_timer: SHOOTING_STAR_INTERVAL.0,
+        }
+    }
+}
+
+impl Starfield {
+    /// Starts a warp at full strength
+    pub fn warp(&mut self) {
+        self.warp = 1.0;
+    }
+
+    /// Returns -> how many times faster than normal the stars scroll
+    pub fn speed_scale(&self) -> f32 {
+        1.0 + WARP_SPEEDUP * self.warp
+    }
+
+    /// Returns -> the share of each layer's stars that is shown
+    pub fn density(&self) -> f32 {
+        BASE_DENSITY + (1.0 - BASE_DENSITY) * self.warp
+    }
+
+    /// Advances the starfield
+    /// Stars that scroll off the bottom come back in at the top at a new column.
+    /// # Arguments
+    /// * `delta_time` - Time elapsed since last update in seconds
+    pub fn update(&mut self, delta_time: f64) {
+        let dt = delta_time as f32;
+        let mut rng = rand::thread_rng();
+
+        self.time += delta_time;
+        self.warp = (self.warp - dt / WARP_DURATION).max(0.0);
+
+        let speed_scale = self.speed_scale();
+        for layer in &mut self.layers {
+            for star in &mut layer.stars {
+                star.y += layer.speed * speed_scale * dt;
+                if star.y > 100.0 {
+                    star.y -= 100.0;
+                    star.x = rng.gen::<f32>() * 100.0;
+                }
+            }
+        }
+
+        for shooting_star in &mut self.shooting_stars {
+            shooting_star.x += SHOOTING_STAR_VELOCITY.0 * dt;
+            shooting_star.y += SHOOTING_STAR_VELOCITY.1 * dt;
+            shooting_star.timer -= dt;
+        }
+        self.shooting_stars.retain(|s| s.timer > 0.0);
+
+        // Shooting stars come more often while warping
+        self.shooting_star_timer -= dt * speed_scale;
+        if self.shooting_star_timer <= 0.0 {
+            self.shooting_stars.push(ShootingStar {
+                x: rng.gen::<f32>() * 60.0,
+                y: rng.gen::<f32>() * 40.0,
+                timer: SHOOTING_STAR_LIFE,
+            });
+            self.shooting_star_timer =
+                rng.gen_range(SHOOTING_STAR_INTERVAL.0..SHOOTING_STAR_INTERVAL.1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test that the starfield has layers scrolling at increasing speeds
+    #[test]
+    fn test_default_layers() {
+        let starfield = Starfield::default();
+
+        assert_eq!(starfield.layers.len(), LAYER_STARS.len());
+        assert!(starfield
+            .layers
+            .windows(2)
+            .all(|pair| pair[0].speed < pair[1].speed));
+        assert_eq!(starfield.density(), BASE_DENSITY);
+    }
+
+    // Test that nearer layers scroll further and stars wrap back to the top
+    #[test]
+    fn test_update_scrolls_layers() {
+        let mut starfield = Starfield::default();
+        for layer in &mut starfield.layers {
+            layer.stars[0].y = 10.0;
+        }
+        starfield.layers[2].stars[1].y = 99.0;
+
+        starfield.update(1.0);
+
+        let far = starfield.layers[0].stars[0].y;
+        let near = starfield.layers[2].stars[0].y;
+        assert_eq!(far, 10.0 + LAYER_SPEEDS[0]);
+        assert!(near > far);
+        assert!(starfield.layers[2].stars[1].y < 99.0);
+        assert_eq!(starfield.time, 1.0);
+    }
+
+    // Test that a warp speeds up and fills in the starfield, then settles
+    #[test]
+    fn test_warp() {
+        let mut starfield = Starfield::default();
+        let layer = starfield.layers[0].clone();
+        let resting = layer.visible(starfield.density()).len();
+
+        starfield.warp();
+        assert_eq!(starfield.speed_scale(), 1.0 + WARP_SPEEDUP);
+        assert_eq!(layer.visible(starfield.density()).len(), layer.stars.len());
+        assert!(resting < layer.stars.len());
+
+        starfield.update(WARP_DURATION as f64 / 2.0);
+        assert!(starfield.warp > 0.0 && starfield.warp < 1.0);
+
+        starfield.update(WARP_DURATION as f64);
+        assert_eq!(starfield.warp, 0.0);
+        assert_eq!(starfield.speed_scale(), 1.0);
+    }
+
+    // Test that stars twinkle below their own opacity
+    #[test]
+    fn test_twinkle() {
+        let star = Star {
+            x: 0.0,
+            y: 0.0,
+            opacity: 0.8,
+            twinkle_phase: 0.0,
+        };
+
+        assert_eq!(star.brightness(0.0), 0.6);
+        assert_ne!(star.brightness(0.5), star.brightness(0.0));
+        assert!((0..100).all(|i| star.brightness(i as f64 * 0.1) <= 0.8));
+    }
+
+    // Test that shooting stars appear, move and fade away
+    #[test]
+    fn test_shooting_stars() {
+        let mut starfield = Starfield {
+            shooting_star_timer: 0.0,
+            ..Default::default()
+        };
+
+        starfield.update(0.01);
+        assert_eq!(starfield.shooting_stars.len(), 1);
+        let start = starfield.shooting_stars[0].clone();
+        assert_eq!(start.opacity(), 1.0);
+
+        starfield.update(0.4);
+        let moved = &starfield.shooting_stars[0];
+        assert!(moved.x > start.x && moved.y > start.y);
+        assert!(moved.opacity() < 1.0);
+
+        starfield.update(0.5);
+        assert!(starfield.shooting_stars.is_empty());
+    }
+}
@@ -1,8 +1,21 @@
+use crate::game::director::DifficultyDirector;
+use crate::game::editor::LevelEditor;
 use crate::game::entities::alien::AlienFormation;
+use crate::game::entities::boss::Boss;
 use crate::game::entities::mystery_ship::MysteryShip;
 use crate::game::entities::player::Player;
 use crate::game::entities::projectile::Projectile;
-use crate::game::entities::shield::Shield;
+use crate::game::entities::shield::{default_shields, Shield};
+use crate::game::events::GameEvent;
+use crate::game::leaderboard::{InitialsEntry, Leaderboard};
+use crate::game::level::{default_level_configs, level_config, LevelConfig};
+use crate::game::level_file::{LevelFile, LevelFileError};
+use crate::game::options::GameOptions;
+use crate::game::scoring::{ComboTracker, ExtraLifeConfig, ScorePopup};
+use crate::game::settings::{ControlsMenu, Settings, SettingsMenu};
+use crate::game::starfield::Starfield;
+use crate::game::transition::LevelTransition;
+use crate::input::bindings::KeyBindings;
 use crate::input::key_states::KeyStates;
 
 // Represents the complete state of the game at any point in time
@@ -30,6 +43,178 @@ pub struct GameState {
     pub mystery_ship: Option<MysteryShip>,
     pub mystery_ship_timer: f32,
     pub screen: GameScreen,
+    pub level_configs: Vec<LevelConfig>,
+    pub extra_life: ExtraLifeConfig,
+    pub extra_life_flash_timer: f64,
+    pub events: Vec<GameEvent>,
+    pub options: GameOptions,
+    pub combo: ComboTracker,
+    pub score_popups: Vec<ScorePopup>,
+    pub shots_fired: u32,
+    pub leaderboard: Leaderboard,
+    pub director: DifficultyDirector,
+    pub boss: Option<Boss>,
+    pub editor: Option<LevelEditor>,
+    pub playtesting: bool,
+    pub paused: bool,
+    pub key_bindings: KeyBindings,
+    pub controls: ControlsMenu,
+    pub settings: Settings,
+    pub settings_menu: SettingsMenu,
+    pub initials: InitialsEntry,
+    /// Where the last game landed on the leaderboard, highlighted in the Hall of Fame
+    pub hall_of_fame_rank: Option<usize>,
+    pub starfield: Starfield,
+    /// The pause between two levels, while it is running
+    pub transition: Option<LevelTransition>,
+    /// The score when the current wave started, for the wave's tally
+    pub wave_start_score: i32,
+}
+
+impl GameState {
+    /// Creates the state for a brand new game
+    /// Everything is reset to the starting values except the high score,
+    /// which is carried over from the previous game. The first wave starts
+    /// with its "WAVE 1" intro.
+    /// # Arguments
+    /// * `high_score` - The high score to keep
+    /// # Returns -> A GameState ready for level 1 (on the start screen)
+    pub fn new_game(high_score: i32) -> Self {
+        let mut state = Self::blank_game(high_score);
+        state.start_wave_intro();
+        state
+    }
+
+    /// Creates the state for a brand new game, before its first wave is shown
+    /// # Arguments
+    /// * `high_score` - The high score to keep
+    /// # Returns -> A GameState for level 1 with the formation in place
+    fn blank_game(high_score: i32) -> Self {
+        let level_configs = default_level_configs();
+        let alien_formation = level_config(&level_configs, 1).build_formation();
+
+        Self {
+            high_score,
+            lives: 3,
+            level: 1,
+            mystery_ship_timer: 15.0,
+            alien_formation,
+            last_update: instant::Instant::now().elapsed().as_secs_f64(),
+            shields: default_shields(),
+            level_configs,
+            extra_life: ExtraLifeConfig::standard(),
+            ..Default::default()
+        }
+    }
+
+    /// Creates the state for the next game, keeping what should survive a restart
+    /// The new game is set up for the selected difficulty and starts with the
+    /// "WAVE 1" intro.
+    /// # Returns -> A new game that keeps this game's high score, options and leaderboard
+    pub fn restart(&self) -> Self {
+        let mut state = self.next_game();
+        state.start_wave_intro();
+        state
+    }
+
+    /// Creates the state for the next game, before its first wave is shown
+    /// # Returns -> A new game that keeps this game's high score, options and leaderboard
+    fn next_game(&self) -> Self {
+        let mut state = Self {
+            options: self.options.clone(),
+            leaderboard: self.leaderboard.clone(),
+            editor: self.editor.clone(),
+            key_bindings: self.key_bindings.clone(),
+            settings: self.settings.clone(),
+            ..Self::blank_game(self.high_score)
+        };
+        state.apply_difficulty();
+        state
+    }
+
+    /// Starts a new game on a single custom level, such as one from the level editor
+    /// The level repeats, getting faster each time, until the game ends.
+    /// # Arguments
+    /// * `level` - The level to play
+    /// * `playtesting` - Whether this is a playtest from the editor
+    /// # Returns -> The new game on the Playing screen, or an error if the level is invalid
+    pub fn start_custom_level(
+        &self,
+        level: &LevelFile,
+        playtesting: bool,
+    ) -> Result<Self, LevelFileError> {
+        let config = level.to_config()?;
+
+        let mut state = Self {
+            screen: GameScreen::Playing,
+            playtesting,
+            ..self.next_game()
+        };
+        state.alien_formation = config.build_formation();
+        if let Some(layout) = &config.layout {
+            state.shields = layout.build_shields();
+        }
+        state.level_configs = vec![config];
+        state.apply_difficulty();
+        state.start_wave_intro();
+        Ok(state)
+    }
+
+    /// Applies saved settings, such as the difficulty to start on
+    /// # Arguments
+    /// * `settings` - The settings to use
+    /// # Returns -> The game state with the settings applied
+    pub fn with_settings(self, settings: Settings) -> Self {
+        Self {
+            options: GameOptions {
+                difficulty: settings.difficulty,
+                ..self.options
+            },
+            settings,
+            ..self
+        }
+    }
+
+    /// Applies the selected difficulty preset to the starting lives and alien formation
+    /// Levels with their own layout keep the layout's formation speed.
+    pub fn apply_difficulty(&mut self) {
+        let preset = self.options.difficulty.preset();
+        let config = level_config(&self.level_configs, self.level);
+        self.lives = preset.starting_lives;
+        self.alien_formation.speed = config.formation_speed(&preset);
+        self.alien_formation.move_interval = config.move_interval(&preset);
+    }
+
+    /// Starts the "WAVE N" intro for the current wave, which waits above the
+    /// playfield and slides into place before play starts
+    pub fn start_wave_intro(&mut self) {
+        let slide_distance = self.wave_bottom();
+        self.shift_wave(-slide_distance);
+        self.transition = Some(LevelTransition::intro(self.level, slide_distance));
+    }
+
+    /// Returns -> the lowest edge of the aliens or boss of the current wave
+    pub fn wave_bottom(&self) -> f32 {
+        let aliens = self
+            .alien_formation
+            .aliens
+            .iter()
+            .map(|alien| alien.position.y + alien.height);
+        let boss = self.boss.iter().map(|boss| boss.position.y + boss.height);
+        aliens.chain(boss).fold(0.0, f32::max)
+    }
+
+    /// Moves the aliens and boss of the current wave down (or up, for a negative distance)
+    /// # Arguments
+    /// * `distance` - How far to move the wave, in pixels
+    pub fn shift_wave(&mut self, distance: f32) {
+        for alien in &mut self.alien_formation.aliens {
+            alien.position.y += distance;
+        }
+        if let Some(boss) = &mut self.boss {
+            boss.position.y += distance;
+        }
+    }
 }
 
 /// Represents the different screens in the game
@@ -38,6 +223,11 @@ pub enum GameScreen {
     StartScreen,
     Playing,
     GameOver,
+    LevelEditor,
+    Controls,
+    Settings,
+    InitialsEntry,
+    HallOfFame,
 }
 
 /// Returns the default screen (StartScreen)
@@ -68,6 +258,125 @@ mod tests {
         assert_eq!(state.invincibility_timer, 0.0);
         assert!(state.mystery_ship.is_none());
         assert_eq!(state.screen, GameScreen::StartScreen);
+        assert_eq!(state.extra_life_flash_timer, 0.0);
+        assert!(state.events.is_empty());
+    }
+
+    // Test that new_game sets up a fresh game and keeps the high score
+    #[test]
+    fn test_new_game() {
+        let state = GameState::new_game(1200);
+        assert_eq!(state.score, 0);
+        assert_eq!(state.high_score, 1200);
+        assert_eq!(state.lives, 3);
+        assert_eq!(state.level, 1);
+        assert_eq!(state.shields.len(), 6);
+        assert!(!state.level_configs.is_empty());
+        assert_eq!(state.alien_formation.count_living(), 55);
+        assert_eq!(state.screen, GameScreen::StartScreen);
+    }
+
+    // Test that a new game opens with the "WAVE 1" intro, its formation waiting above the playfield
+    #[test]
+    fn test_new_game_intro() {
+        use crate::game::transition::TransitionPhase;
+
+        let state = GameState::new_game(0);
+        let transition = state.transition.clone().unwrap();
+
+        assert_eq!(transition.phase(), TransitionPhase::Intro);
+        assert_eq!(transition.next_level(), 1);
+        assert!(state
+            .alien_formation
+            .aliens
+            .iter()
+            .all(|alien| alien.position.y + alien.height <= 0.0));
+    }
+
+    // Test that restart keeps the high score and options but nothing else
+    #[test]
+    fn test_restart() {
+        let mut state = GameState::new_game(0);
+        state.score = 700;
+        state.high_score = 900;
+        state.lives = 1;
+        state.options.combo_scoring = true;
+        state
+            .key_bindings
+            .clear(crate::input::bindings::Action::Pause);
+
+        let restarted = state.restart();
+
+        assert_eq!(restarted.score, 0);
+        assert_eq!(restarted.high_score, 900);
+        assert_eq!(restarted.lives, 3);
+        assert!(restarted.options.combo_scoring);
+        assert!(restarted.key_bindings.pause.is_empty());
+    }
+
+    // Test that restarting sets up the game for the selected difficulty
+    #[test]
+    fn test_restart_applies_difficulty() {
+        use crate::game::difficulty::Difficulty;
+
+        let mut state = GameState::new_game(0);
+        state.options.difficulty = Difficulty::Easy;
+
+        let restarted = state.restart();
+        let preset = Difficulty::Easy.preset();
+
+        assert_eq!(restarted.lives, preset.starting_lives);
+        assert_eq!(restarted.alien_formation.speed, preset.formation_speed);
+        assert_eq!(
+            restarted.alien_formation.move_interval,
+            preset.move_interval
+        );
+    }
+
+    // Test that saved settings choose the starting difficulty and survive restarts
+    #[test]
+    fn test_with_settings() {
+        use crate::game::difficulty::Difficulty;
+
+        let settings = Settings {
+            difficulty: Difficulty::Hard,
+            show_fps: true,
+            ..Default::default()
+        };
+        let state = GameState::new_game(0).with_settings(settings.clone());
+
+        assert_eq!(state.options.difficulty, Difficulty::Hard);
+        assert_eq!(state.restart().settings, settings);
+    }
+
+    // Test that a custom level replaces the campaign with its own layout
+    #[test]
+    fn test_start_custom_level() {
+        let mut level = LevelFile::default();
+        level.layout.aliens.truncate(3);
+        level.layout.shields.truncate(2);
+        level.layout.formation_speed = 35.0;
+
+        let state = GameState::new_game(500)
+            .start_custom_level(&level, true)
+            .unwrap();
+
+        assert_eq!(state.screen, GameScreen::Playing);
+        assert!(state.playtesting);
+        assert_eq!(state.high_score, 500);
+        assert_eq!(state.alien_formation.count_living(), 3);
+        assert_eq!(state.alien_formation.speed, 35.0);
+        assert_eq!(state.shields.len(), 2);
+        assert_eq!(state.level_configs.len(), 1);
+
+        let transition = state.transition.clone().unwrap();
+        let placed = state.level_configs[0].build_formation();
+        for (alien, placed) in state.alien_formation.aliens.iter().zip(&placed.aliens) {
+            assert_eq!(
+                alien.position.y + transition.slide_distance,
+                placed.position.y
+            );
+        }
     }
 
     // Test that GameScreen default is StartScreen
@@ -0,0 +1,161 @@
+use crate::game::director::LevelStats;
+
+/// Seconds the score tally for the cleared wave is shown
+pub const TALLY_DURATION: f64 = 2.5;
+/// Seconds the new formation takes to slide into place under the "WAVE N" banner
+pub const INTRO_DURATION: f64 = 1.5;
+
+/// What the level transition is currently showing
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum TransitionPhase {
+    /// The tally of the wave that was just cleared
+    Tally,
+    /// The "WAVE N" banner, while the new formation slides in
+    Intro,
+}
+
+/// The pause between two levels
+/// While it runs the game does not update the player, aliens or projectiles.
+/// The new formation is already in `GameState::alien_formation`, moved up by
+/// `offset` so that it can slide down into its starting place.
+#[derive(Clone, PartialEq, Debug)]
+pub struct LevelTransition {
+    /// The level that was just cleared
+    pub cleared_level: i32,
+    /// Points scored during the cleared wave
+    pub points: i32,
+    /// Shots, hits and time of the cleared wave
+    pub stats: LevelStats,
+    /// Seconds since the level was cleared
+    pub elapsed: f64,
+    /// How far above its place the new formation starts
+    pub slide_distance: f32,
+    /// How far above its place the new formation is now
+    pub offset: f32,
+}
+
+impl LevelTransition {
+    /// Starts the transition after a level is cleared
+    /// # Arguments
+    /// * `cleared_level` - The level that was just cleared
+    /// * `points` - Points scored during the cleared wave
+    /// * `stats` - Shots, hits and time of the cleared wave
+    /// * `slide_distance` - How far above its place the new formation starts
+    /// # Returns -> A transition at the start of the tally
+    pub fn new(cleared_level: i32, points: i32, stats: LevelStats, slide_distance: f32) -> Self {
+        Self {
+            cleared_level,
+            points,
+            stats,
+            elapsed: 0.0,
+            slide_distance,
+            offset: slide_distance,
+        }
+    }
+
+    /// Starts the transition at the "WAVE N" banner, for the first wave of a game
+    /// # Arguments
+    /// * `level` - The level that is about to start
+    /// * `slide_distance` - How far above its place the formation starts
+    /// # Returns -> A transition at the start of the intro, with no tally to show
+    pub fn intro(level: i32, slide_distance: f32) -> Self {
+        Self {
+            elapsed: TALLY_DURATION,
+            ..Self::new(level - 1, 0, LevelStats::default(), slide_distance)
+        }
+    }
+
+    /// Returns -> the level that starts when the transition ends
+    pub fn next_level(&self) -> i32 {
+        self.cleared_level + 1
+    }
+
+    /// Returns -> what the transition is currently showing
+    pub fn phase(&self) -> TransitionPhase {
+        if self.elapsed < TALLY_DURATION {
+            TransitionPhase::Tally
+        } else {
+            TransitionPhase::Intro
+        }
+    }
+
+    /// Returns -> `true` once the new formation is in place and play can resume
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= TALLY_DURATION + INTRO_DURATION
+    }
+
+    /// Advances the transition
+    /// The formation slides in during the intro, slowing down as it arrives.
+    /// # Arguments
+    /// * `delta_time` - Time elapsed since last update in seconds
+    /// # Returns -> How far the new formation moves down this frame
+    pub fn update(&mut self, delta_time: f64) -> f32 {
+        self.elapsed += delta_time;
+
+        let progress = ((self.elapsed - TALLY_DURATION) / INTRO_DURATION).clamp(0.0, 1.0) as f32;
+        let eased = 1.0 - (1.0 - progress) * (1.0 - progress);
+        let offset = self.slide_distance * (1.0 - eased);
+        let moved = self.offset - offset;
+        self.offset = offset;
+        moved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test that the tally is shown before the intro, then the transition ends
+    #[test]
+    fn test_phases() {
+        let mut transition = LevelTransition::new(3, 1200, LevelStats::default(), 300.0);
+        assert_eq!(transition.phase(), TransitionPhase::Tally);
+        assert_eq!(transition.next_level(), 4);
+
+        transition.update(TALLY_DURATION);
+        assert_eq!(transition.phase(), TransitionPhase::Intro);
+        assert!(!transition.is_finished());
+
+        transition.update(INTRO_DURATION);
+        assert!(transition.is_finished());
+    }
+
+    // Test that the first wave skips the tally and shows its own banner
+    #[test]
+    fn test_intro() {
+        let mut transition = LevelTransition::intro(1, 300.0);
+        assert_eq!(transition.phase(), TransitionPhase::Intro);
+        assert_eq!(transition.next_level(), 1);
+        assert_eq!(transition.offset, 300.0);
+
+        assert!(transition.update(INTRO_DURATION / 2.0) > 0.0);
+        transition.update(INTRO_DURATION / 2.0);
+        assert!(transition.is_finished());
+        assert_eq!(transition.offset, 0.0);
+    }
+
+    // Test that the formation only slides during the intro and ends up in place
+    #[test]
+    fn test_slide() {
+        let mut transition = LevelTransition::new(1, 0, LevelStats::default(), 300.0);
+
+        assert_eq!(transition.update(TALLY_DURATION / 2.0), 0.0);
+        assert_eq!(transition.offset, 300.0);
+
+        let mut moved = 0.0;
+        let mut last_step = f32::MAX;
+        transition.update(TALLY_DURATION / 2.0);
+        for _ in 0..10 {
+            let step = transition.update(INTRO_DURATION / 10.0);
+            assert!(step > 0.0 && step <= last_step);
+            last_step = step;
+            moved += step;
+        }
+
+        assert!(transition.offset < 0.01);
+        assert!((moved - 300.0).abs() < 0.01);
+
+        transition.update(INTRO_DURATION);
+        assert_eq!(transition.offset, 0.0);
+    }
+}
@@ -0,0 +1,319 @@
+use crate::utils::i18n::{self, Language};
+use crate::utils::storage;
+use dioxus::events::Key;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// The name the key bindings are saved under
+pub const CONTROLS_FILE: &str = "controls.toml";
+
+/// Something the player can do with a key
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Action {
+    MoveLeft,
+    MoveRight,
+    Fire,
+    Pause,
+    Confirm,
+    Back,
+}
+
+impl Action {
+    /// Every action, in the order the Controls screen lists them
+    pub const ALL: [Action; 6] = [
+        Action::MoveLeft,
+        Action::MoveRight,
+        Action::Fire,
+        Action::Pause,
+        Action::Confirm,
+        Action::Back,
+    ];
+
+    /// Returns -> the label shown for the action on the Controls screen, in the given language
+    pub fn label(&self, language: Language) -> String {
+        let key = match self {
+            Action::MoveLeft => "action.move_left",
+            Action::MoveRight => "action.move_right",
+            Action::Fire => "action.fire",
+            Action::Pause => "action.pause",
+            Action::Confirm => "action.confirm",
+            Action::Back => "action.back",
+        };
+        i18n::text(language, key)
+    }
+}
+
+/// Returns -> the name a key is stored under
+/// Letters are lowercased so that holding Shift does not change which action
+/// a letter key triggers.
+pub fn key_name(key: &Key) -> String {
+    match key {
+        Key::Character(c) => c.to_lowercase(),
+        _ => key.to_string(),
+    }
+}
+
+/// Returns -> a key name in the form shown to the player, e.g. `SPACE` or `LEFT`
+/// Keys with a name in the message catalog are translated; any other key is
+/// shown as its uppercased name.
+/// # Arguments
+/// * `name` - The name the key is stored under
+/// * `language` - The language to show the name in
+pub fn key_label(name: &str, language: Language) -> String {
+    let key = match name {
+        " " => "key.space",
+        "ArrowLeft" => "key.left",
+        "ArrowRight" => "key.right",
+        "ArrowUp" => "key.up",
+        "ArrowDown" => "key.down",
+        "Enter" => "key.enter",
+        "Escape" => "key.escape",
+        "Shift" => "key.shift",
+        "Tab" => "key.tab",
+        _ => return name.to_uppercase(),
+    };
+    i18n::text(language, key)
+}
+
+/// Which keys trigger each action
+/// Every action can have several keys, and a key belongs to at most one action.
+/// The bindings are stored as TOML, one list of key names per action:
+///
+/// ```toml
+/// move_left = ["ArrowLeft", "a"]
+/// fire = ["Shift", " "]
+/// ```
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KeyBindings {
+    pub move_left: Vec<String>,
+    pub move_right: Vec<String>,
+    pub fire: Vec<String>,
+    pub pause: Vec<String>,
+    pub confirm: Vec<String>,
+    pub back: Vec<String>,
+}
+
+impl Default for KeyBindings {
+    /// Creates the standard bindings: arrows or A/D to move, Shift or Space to fire
+    fn default() -> Self {
+        let keys = |names: &[&str]| names.iter().map(|n| n.to_string()).collect();
+
+        Self {
+            move_left: keys(&["ArrowLeft", "a"]),
+            move_right: keys(&["ArrowRight", "d"]),
+            fire: keys(&["Shift", " "]),
+            pause: keys(&["p"]),
+            confirm: keys(&["Enter", "Tab"]),
+            back: keys(&["Escape"]),
+        }
+    }
+}
+
+impl KeyBindings {
+    /// Returns -> the names of the keys bound to an action
+    pub fn keys(&self, action: Action) -> &Vec<String> {
+        match action {
+            Action::MoveLeft => &self.move_left,
+            Action::MoveRight => &self.move_right,
+            Action::Fire => &self.fire,
+            Action::Pause => &self.pause,
+            Action::Confirm => &self.confirm,
+            Action::Back => &self.back,
+        }
+    }
+
+    fn keys_mut(&mut self, action: Action) -> &mut Vec<String> {
+        match action {
+            Action::MoveLeft => &mut self.move_left,
+            Action::MoveRight => &mut self.move_right,
+            Action::Fire => &mut self.fire,
+            Action::Pause => &mut self.pause,
+            Action::Confirm => &mut self.confirm,
+            Action::Back => &mut self.back,
+        }
+    }
+
+    /// Returns -> the keys bound to an action as shown to the player, e.g. `LEFT / A`
+    /// # Arguments
+    /// * `action` - The action whose keys are listed
+    /// * `language` - The language key names are shown in
+    pub fn label(&self, action: Action, language: Language) -> String {
+        match self.keys(action).as_slice() {
+            [] => "-".to_string(),
+            names => names
+                .iter()
+                .map(|name| key_label(name, language))
+                .collect::<Vec<_>>()
+                .join(" / "),
+        }
+    }
+
+    /// Finds the action a key is bound to
+    /// # Arguments
+    /// * `key` - The key that was pressed or released
+    /// # Returns -> The bound action, or None if the key does nothing
+    pub fn action_for(&self, key: &Key) -> Option<Action> {
+        let name = key_name(key);
+        Action::ALL
+            .into_iter()
+            .find(|action| self.keys(*action).contains(&name))
+    }
+
+    /// Returns -> `true` if the key is bound to the action
+    pub fn is(&self, key: &Key, action: Action) -> bool {
+        self.keys(action).contains(&key_name(key))
+    }
+
+    /// Adds a key to an action, taking it away from any other action first
+    /// # Arguments
+    /// * `action` - The action to bind
+    /// * `key` - The key to bind to it
+    pub fn bind(&mut self, action: Action, key: &Key) {
+        let name = key_name(key);
+        for other in Action::ALL {
+            self.keys_mut(other).retain(|k| *k != name);
+        }
+        self.keys_mut(action).push(name);
+    }
+
+    /// Removes every key from an action
+    pub fn clear(&mut self, action: Action) {
+        self.keys_mut(action).clear();
+    }
+
+    /// Gives actions that were left without keys their default keys back,
+    /// skipping any default key that is now used by another action
+    pub fn fill_missing(&mut self) {
+        let defaults = Self::default();
+        for action in Action::ALL {
+            if !self.keys(action).is_empty() {
+                continue;
+            }
+            let free: Vec<String> = defaults
+                .keys(action)
+                .iter()
+                .filter(|name| Action::ALL.iter().all(|a| !self.keys(*a).contains(name)))
+                .cloned()
+                .collect();
+            *self.keys_mut(action) = free;
+        }
+    }
+
+    /// Reads bindings from their TOML text
+    /// Key names that are not recognized are dropped.
+    /// # Arguments
+    /// * `source` - The contents of a controls file
+    /// # Returns -> The bindings, or the TOML error message
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let mut bindings: Self = toml::from_str(source).map_err(|e| e.message().to_string())?;
+        for action in Action::ALL {
+            bindings
+                .keys_mut(action)
+                .retain(|name| Key::from_str(name).is_ok());
+        }
+        bindings.fill_missing();
+        Ok(bindings)
+    }
+
+    /// Returns -> the bindings as TOML text
+    pub fn to_toml(&self) -> String {
+        toml::to_string_pretty(self).expect("key bindings only contain strings")
+    }
+
+    /// Loads the saved bindings, falling back to the defaults if there are none
+    /// or the file cannot be read
+    pub fn load() -> Self {
+        storage::load(CONTROLS_FILE)
+            .ok()
+            .and_then(|source| Self::parse(&source).ok())
+            .unwrap_or_default()
+    }
+
+    /// Saves the bindings so they are used the next time the game starts
+    /// # Returns -> `Ok(())` on success, or the I/O error that occurred
+    pub fn save(&self) -> std::io::Result<()> {
+        storage::save(CONTROLS_FILE, &self.to_toml())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test that the default bindings map keys to the expected actions
+    #[test]
+    fn test_default_bindings() {
+        let bindings = KeyBindings::default();
+
+        assert_eq!(bindings.action_for(&Key::ArrowLeft), Some(Action::MoveLeft));
+        assert_eq!(
+            bindings.action_for(&Key::Character("D".to_string())),
+            Some(Action::MoveRight)
+        );
+        assert_eq!(
+            bindings.action_for(&Key::Character(" ".to_string())),
+            Some(Action::Fire)
+        );
+        assert!(bindings.is(&Key::Tab, Action::Confirm));
+        assert_eq!(bindings.action_for(&Key::Backspace), None);
+    }
+
+    // Test that binding a key moves it away from its old action
+    #[test]
+    fn test_bind_and_clear() {
+        let mut bindings = KeyBindings::default();
+
+        bindings.bind(Action::Fire, &Key::Character("a".to_string()));
+        assert_eq!(bindings.move_left, vec!["ArrowLeft".to_string()]);
+        assert_eq!(
+            bindings.action_for(&Key::Character("a".to_string())),
+            Some(Action::Fire)
+        );
+
+        bindings.clear(Action::Pause);
+        assert!(bindings.pause.is_empty());
+        bindings.fill_missing();
+        assert_eq!(bindings.pause, vec!["p".to_string()]);
+    }
+
+    // Test that bindings survive a round trip through TOML
+    #[test]
+    fn test_round_trip() {
+        let mut bindings = KeyBindings::default();
+        bindings.bind(Action::Pause, &Key::Escape);
+
+        let parsed = KeyBindings::parse(&bindings.to_toml()).unwrap();
+        assert_eq!(parsed, bindings);
+    }
+
+    // Test that missing actions and unknown keys fall back sensibly
+    #[test]
+    fn test_parse_partial_file() {
+        let bindings = KeyBindings::parse(r#"fire = ["x", "NotAKey"]"#).unwrap();
+
+        assert_eq!(bindings.fire, vec!["x".to_string()]);
+        assert_eq!(bindings.move_left, KeyBindings::default().move_left);
+        assert!(KeyBindings::parse("fire = 3").is_err());
+    }
+
+    // Test that key labels follow the bindings and the language
+    #[test]
+    fn test_labels() {
+        let mut bindings = KeyBindings::default();
+        bindings.bind(Action::Fire, &Key::Character("j".to_string()));
+
+        assert_eq!(
+            bindings.label(Action::MoveLeft, Language::English),
+            "LEFT / A"
+        );
+        assert_eq!(
+            bindings.label(Action::Fire, Language::French),
+            "MAJ / ESPACE / J"
+        );
+        assert_eq!(Action::Back.label(Language::Spanish), "ATRÁS");
+
+        bindings.clear(Action::Pause);
+        assert_eq!(bindings.label(Action::Pause, Language::English), "-");
+    }
+}
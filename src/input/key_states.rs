@@ -1,7 +1,12 @@
+use crate::input::bindings::{Action, KeyBindings};
 use dioxus::events::Key;
 /// Tracks the state of keyboard inputs relevant to the game
 /// This struct maintains boolean flags for each key that the game responds to,
 /// allowing the game logic to easily check which keys are currently pressed.
+/// Input events are queued as they arrive and applied at the start of the next
+/// tick, which also records what was pressed and released during that tick, so
+/// a tap that starts and ends between two ticks is never lost. Menus and text
+/// entry read the keys typed during the tick instead, which include key repeats.
 
 #[derive(Default, Clone, PartialEq, Debug)]
 pub struct KeyStates {
@@ -10,13 +15,24 @@ pub struct KeyStates {
     pub shift: bool,
     pub tab: bool,
     pub enter: bool,
+    /// Where the pointer is over the game area, when pointer control is on
+    pub pointer_x: Option<f32>,
+    /// Actions pressed since the previous tick
+    pub just_pressed: Vec<Action>,
+    /// Actions released since the previous tick
+    pub just_released: Vec<Action>,
+    /// Input events waiting for the next tick, oldest first
+    pub buffer: Vec<(Action, bool)>,
+    /// Keys typed since the previous tick, oldest first
+    pub just_typed: Vec<Key>,
+    /// Typed keys waiting for the next tick, oldest first
+    pub typed_buffer: Vec<Key>,
 }
 
 /// Creates a new KeyStates instance with all keys unpressed
 /// # Returns -> A new KeyStates instance with all boolean flags set to false
 
 impl KeyStates {
-    #[allow(dead_code)]
     pub fn new() -> Self {
         Self {
             left: false,
@@ -24,25 +40,77 @@ impl KeyStates {
             shift: false,
             tab: false,
             enter: false,
+            pointer_x: None,
+            just_pressed: Vec::new(),
+            just_released: Vec::new(),
+            buffer: Vec::new(),
+            just_typed: Vec::new(),
+            typed_buffer: Vec::new(),
         }
     }
 
-    /// Updates the state of a specific key based on keyboard events
+    /// Queues the action a key is bound to, to be applied at the start of the next tick
+    /// Keys that are not bound are ignored.
     /// # Arguments
+    /// * `bindings` - The player's key bindings
     /// * `key` - The key that was pressed or released
     /// * `pressed` - Whether the key was pressed (true) or released (false)
+    pub fn update(&mut self, bindings: &KeyBindings, key: &Key, pressed: bool) {
+        if let Some(action) = bindings.action_for(key) {
+            self.queue(action, pressed);
+        }
+    }
+
+    /// Updates the state of an action, whichever input device triggered it
+    /// # Arguments
+    /// * `action` - The action that was triggered
+    /// * `pressed` - Whether the input was pressed (true) or released (false)
+    pub fn update_from_action(&mut self, action: Action, pressed: bool) {
+        match action {
+            Action::MoveLeft => self.left = pressed,
+            Action::MoveRight => self.right = pressed,
+            Action::Fire => self.shift = pressed,
+            Action::Confirm => self.enter = pressed,
+            Action::Pause | Action::Back => {}
+        }
+    }
 
-    pub fn update_from_key(&mut self, key: dioxus::events::Key, pressed: bool) {
-        match key {
-            Key::ArrowLeft => self.left = pressed,
-            Key::ArrowRight => self.right = pressed,
-            Key::Shift => self.shift = pressed,
-            Key::Tab => self.tab = pressed,
-            Key::Enter => self.enter = pressed,
+    /// Queues an input event to be applied at the start of the next tick
+    /// # Arguments
+    /// * `action` - The action that was triggered
+    /// * `pressed` - Whether the input was pressed (true) or released (false)
+    pub fn queue(&mut self, action: Action, pressed: bool) {
+        self.buffer.push((action, pressed));
+    }
+
+    /// Queues a typed key to be handed to the current screen at the start of the next tick
+    /// # Arguments
+    /// * `key` - The key that was pressed, or repeated while held down
+    pub fn type_key(&mut self, key: Key) {
+        self.typed_buffer.push(key);
+    }
 
-            _ => {}
+    /// Starts a new tick: applies the queued events and records which actions
+    /// were pressed and released, and which keys were typed, since the previous tick
+    pub fn begin_tick(&mut self) {
+        self.just_pressed.clear();
+        self.just_released.clear();
+        self.just_typed = std::mem::take(&mut self.typed_buffer);
+
+        for (action, pressed) in std::mem::take(&mut self.buffer) {
+            self.update_from_action(action, pressed);
+            if pressed {
+                self.just_pressed.push(action);
+            } else {
+                self.just_released.push(action);
+            }
         }
     }
+
+    /// Returns -> `true` if the action was pressed since the previous tick
+    pub fn is_just_pressed(&self, action: Action) -> bool {
+        self.just_pressed.contains(&action)
+    }
 }
 
 #[cfg(test)]
@@ -72,31 +140,90 @@ mod tests {
         assert!(!key_states.enter);
     }
 
-    // Test that update_from_key correctly updates key states
+    // Test that update follows the key bindings
     #[test]
-    fn test_update_from_key() {
+    fn test_update_with_bindings() {
         let mut key_states = KeyStates::default();
+        let mut bindings = KeyBindings::default();
+        bindings.bind(Action::Fire, &Key::Character("j".to_string()));
 
-        key_states.update_from_key(Key::ArrowLeft, true);
+        key_states.update(&bindings, &Key::Character("A".to_string()), true);
+        assert!(!key_states.left);
+        key_states.begin_tick();
         assert!(key_states.left);
 
-        key_states.update_from_key(Key::ArrowRight, true);
-        assert!(key_states.right);
-
-        key_states.update_from_key(Key::Shift, true);
+        key_states.update(&bindings, &Key::Character("j".to_string()), true);
+        key_states.begin_tick();
         assert!(key_states.shift);
 
-        key_states.update_from_key(Key::Tab, true);
-        assert!(key_states.tab);
+        key_states.update(&bindings, &Key::Character("p".to_string()), true);
+        key_states.update(&bindings, &Key::Character("a".to_string()), false);
+        key_states.begin_tick();
+        assert!(!key_states.left);
+        assert_eq!(key_states.just_pressed, vec![Action::Pause]);
+        assert_eq!(key_states.just_released, vec![Action::MoveLeft]);
+        assert_eq!(
+            key_states,
+            KeyStates {
+                shift: true,
+                just_pressed: vec![Action::Pause],
+                just_released: vec![Action::MoveLeft],
+                ..Default::default()
+            }
+        );
+    }
 
-        key_states.update_from_key(Key::Enter, true);
-        assert!(key_states.enter);
+    // Test that queued events are applied on the next tick and only reported for that tick
+    #[test]
+    fn test_edge_triggered_input() {
+        let mut key_states = KeyStates::default();
 
-        key_states.update_from_key(Key::ArrowLeft, false);
+        key_states.queue(Action::MoveLeft, true);
         assert!(!key_states.left);
 
-        key_states.update_from_key(Key::ArrowRight, false);
-        assert!(!key_states.right);
+        key_states.begin_tick();
+        assert!(key_states.left);
+        assert!(key_states.is_just_pressed(Action::MoveLeft));
+
+        key_states.begin_tick();
+        assert!(key_states.left);
+        assert!(!key_states.is_just_pressed(Action::MoveLeft));
+
+        key_states.queue(Action::MoveLeft, false);
+        key_states.begin_tick();
+        assert!(!key_states.left);
+        assert_eq!(key_states.just_released, vec![Action::MoveLeft]);
+    }
+
+    // Test that a tap between two ticks is still seen as a press
+    #[test]
+    fn test_tap_between_ticks() {
+        let mut key_states = KeyStates::default();
+
+        key_states.queue(Action::Fire, true);
+        key_states.queue(Action::Fire, false);
+        key_states.begin_tick();
+
+        assert!(!key_states.shift);
+        assert!(key_states.is_just_pressed(Action::Fire));
+        assert_eq!(key_states.just_released, vec![Action::Fire]);
+        assert!(key_states.buffer.is_empty());
+    }
+
+    // Test that typed keys are handed over on the next tick, repeats included
+    #[test]
+    fn test_typed_keys() {
+        let mut key_states = KeyStates::default();
+
+        key_states.type_key(Key::ArrowDown);
+        key_states.type_key(Key::ArrowDown);
+        assert!(key_states.just_typed.is_empty());
+
+        key_states.begin_tick();
+        assert_eq!(key_states.just_typed, vec![Key::ArrowDown, Key::ArrowDown]);
+
+        key_states.begin_tick();
+        assert!(key_states.just_typed.is_empty());
     }
 
     // Test that unhandled keys don't affect the state
@@ -104,7 +231,8 @@ mod tests {
     fn test_unhandled_keys() {
         let mut key_states = KeyStates::default();
 
-        key_states.update_from_key(Key::Backspace, true);
+        key_states.update(&KeyBindings::default(), &Key::Backspace, true);
+        key_states.begin_tick();
 
         assert!(!key_states.left);
         assert!(!key_states.right);
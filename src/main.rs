@@ -2,12 +2,7 @@ use dioxus::prelude::*;
 use dioxus_desktop::tao::dpi::LogicalSize;
 use dioxus_desktop::tao::window::WindowBuilder;
 
-use rendering::game::Game;
-
-mod game;
-mod input;
-mod rendering;
-mod utils;
+use space_invaders::rendering::game::Game;
 
 const MAIN_CSS: Asset = asset!("/assets/styling/main.css");
 
@@ -0,0 +1,53 @@
+use crate::game::settings::ControlsMenu;
+use crate::input::bindings::{Action, KeyBindings};
+use crate::utils::i18n::{self, Language};
+use dioxus::prelude::*;
+
+/// Displays the Controls screen: every action with the keys bound to it
+#[derive(Props, Clone, PartialEq, Debug)]
+pub struct ControlsScreenProps {
+    pub bindings: KeyBindings,
+    pub menu: ControlsMenu,
+    pub language: Language,
+}
+
+#[component]
+#[allow(non_snake_case)]
+pub fn ControlsScreen(props: ControlsScreenProps) -> Element {
+    let language = props.language;
+    let rows = Action::ALL.into_iter().enumerate().map(|(index, action)| {
+        let selected = index == props.menu.selected;
+        let class = if selected {
+            "controls-row selected"
+        } else {
+            "controls-row"
+        };
+        let keys = if selected && props.menu.capturing {
+            i18n::text(language, "controls.press_key")
+        } else {
+            props.bindings.label(action, language)
+        };
+
+        rsx! {
+            div { class,
+                span { class: "controls-action", {action.label(language)} }
+                span { class: "controls-keys", "{keys}" }
+            }
+        }
+    });
+
+    rsx! {
+        div { id: "controls-screen",
+            div { class: "title", {i18n::text(language, "controls.title")} }
+            div { class: "controls-list", {rows} }
+            if let Some(message) = &props.menu.message {
+                p { class: "controls-message", "{message}" }
+            }
+            div { class: "instructions",
+                p { {i18n::text(language, "controls.help_select")} }
+                p { {i18n::text(language, "controls.help_clear")} }
+                p { {i18n::text(language, "controls.help_leave")} }
+            }
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use instant::Instant;
+
+/// Weight of the newest frame in the smoothed frame time
+const SMOOTHING: f64 = 0.1;
+
+/// Measures how often the game is actually drawn
+/// `frame` is called each time the game component renders. The next render
+/// cannot start before the previous one has been diffed and applied, so the
+/// time between two calls includes the renderer's work and not just the game
+/// loop's tick.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FrameTimer {
+    last_frame: Option<Instant>,
+    /// Smoothed seconds between two rendered frames, 0.0 until two frames were seen
+    pub frame_time: f64,
+}
+
+impl FrameTimer {
+    /// Records a rendered frame
+    /// # Arguments
+    /// * `now` - When the frame started rendering
+    pub fn frame(&mut self, now: Instant) {
+        if let Some(last_frame) = self.last_frame {
+            let elapsed = (now - last_frame).as_secs_f64();
+            self.frame_time = if self.frame_time > 0.0 {
+                self.frame_time * (1.0 - SMOOTHING) + elapsed * SMOOTHING
+            } else {
+                elapsed
+            };
+        }
+        self.last_frame = Some(now);
+    }
+
+    /// Returns -> the smoothed frames per second, or 0.0 before the second frame
+    pub fn fps(&self) -> f64 {
+        if self.frame_time > 0.0 {
+            1.0 / self.frame_time
+        } else {
+            0.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    // Test that the frame time follows the time between rendered frames
+    #[test]
+    fn test_frame_time() {
+        let mut timer = FrameTimer::default();
+        let start = Instant::now();
+
+        timer.frame(start);
+        assert_eq!(timer.fps(), 0.0);
+
+        timer.frame(start + Duration::from_millis(20));
+        assert!((timer.frame_time - 0.02).abs() < 1e-9);
+        assert!((timer.fps() - 50.0).abs() < 1e-6);
+
+        for i in 2..200 {
+            timer.frame(start + Duration::from_millis(20 + (i - 1) * 40));
+        }
+        assert!((timer.frame_time - 0.04).abs() < 1e-4);
+    }
+}
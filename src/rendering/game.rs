@@ -1,45 +1,53 @@
-use crate::game::entities::alien::AlienFormation;
-use crate::game::entities::shield::{Shield, ShieldType};
+use crate::game::editor::{load_custom_level, EditorTool, LevelEditor};
+use crate::game::entities::alien::AlienType;
+use crate::game::entities::mystery_ship::MysteryShipScoring;
+use crate::game::entities::shield::ShieldType;
+use crate::game::events::GameEvent;
 use crate::game::logic::check_projectile_collisions;
 use crate::game::logic::{
-    check_game_over_conditions, check_level_completion, handle_alien_shooting,
-    handle_player_shooting, update_aliens, update_mystery_ship, update_player, update_projectiles,
+    check_game_over_conditions, check_level_completion, finish_game, handle_alien_shooting,
+    handle_input, handle_player_shooting, submit_initials, update_aliens, update_boss,
+    update_dives, update_hud, update_level_transition, update_mystery_ship, update_player,
+    update_projectiles, update_starfield,
 };
+use crate::game::scoring::SCORE_POPUP_DURATION;
+use crate::game::settings::{ControlsMenu, Settings, SettingsMenu, SettingsRow};
 use crate::game::state::{GameScreen, GameState};
+use crate::input::bindings::{key_name, Action, KeyBindings};
+use crate::rendering::controls_screen::ControlsScreen;
+use crate::rendering::frame_timer::FrameTimer;
+use crate::rendering::level_editor::LevelEditorScreen;
 use crate::rendering::score_board::ScoreBoard;
-use crate::rendering::screens::{GameOverScreen, StartScreen};
-use crate::rendering::sprites::alien_sprite::AlienSprite;
+use crate::rendering::screens::{
+    GameOverScreen, HallOfFameScreen, InitialsEntryScreen, LevelTransitionBanner, StartScreen,
+};
+use crate::rendering::settings_screen::SettingsScreen;
+use crate::rendering::sprites::alien_sprite::{AlienSprite, AlienView};
+use crate::rendering::sprites::boss_sprite::{BossHealthBar, BossSprite};
 use crate::rendering::sprites::mystery_ship_sprite::MysteryShipSprite;
 use crate::rendering::sprites::player_sprite::PlayerShip;
 use crate::rendering::sprites::projectile_sprite::ProjectileSprite;
 use crate::rendering::sprites::shield_sprite::ShieldSprite;
 use crate::rendering::star_background::StarBackground;
+use crate::rendering::svg_playfield::{svg_playfield, SVG_RENDERER};
+use crate::rendering::touch_controls::{InitialsTouchControls, TouchControls, TOUCH_CONTROLS};
+use crate::utils::i18n;
 use dioxus::events::KeyboardEvent;
 use dioxus::prelude::*;
+use std::cell::Cell;
+use std::rc::Rc;
 
 /// Main game component that handles rendering and game loop
 /// This component initializes the game state, sets up the game loop using coroutines,
 /// handles keyboard input, and renders the appropriate screen based on the current game state.
 #[allow(non_snake_case)]
 pub fn Game() -> Element {
-    const GAME_WIDTH: f32 = 1024.0;
-    const _GAME_HEIGHT: f32 = 768.0;
-    let mut game_state = use_signal(|| GameState {
-        lives: 3,
-        level: 1,
-        mystery_ship_timer: 15.0,
-        screen: GameScreen::StartScreen,
-        alien_formation: AlienFormation::new(GAME_WIDTH),
-        last_update: instant::Instant::now().elapsed().as_secs_f64(),
-        shields: vec![
-            Shield::new(100.0, 500.0, ShieldType::UppercaseC),
-            Shield::new(250.0, 500.0, ShieldType::UppercaseO),
-            Shield::new(400.0, 500.0, ShieldType::UppercaseR),
-            Shield::new(550.0, 500.0, ShieldType::UppercaseT),
-            Shield::new(700.0, 500.0, ShieldType::UppercaseW),
-            Shield::new(850.0, 500.0, ShieldType::UppercaseO),
-        ],
-        ..Default::default()
+    let mut game_state = use_signal(|| {
+        GameState {
+            key_bindings: KeyBindings::load(),
+            ..GameState::new_game(0)
+        }
+        .with_settings(Settings::load())
     });
 
     use_coroutine(move |_rx: dioxus::prelude::UnboundedReceiver<()>| {
@@ -54,14 +62,23 @@ pub fn Game() -> Element {
                 last_frame = now;
 
                 game_state.with_mut(|state| {
-                    if state.screen == GameScreen::Playing {
-                        if !state.game_over {
-                            state.dt = delta_time as f32;
+                    handle_input(state);
+                    handle_typed_keys(state);
+
+                    if state.screen == GameScreen::Playing && !state.paused && !state.game_over {
+                        state.dt = delta_time as f32;
+                        state.events.clear();
+                        if state.transition.is_some() {
+                            update_level_transition(state, delta_time);
+                        } else {
+                            state.director.stats.time += delta_time;
                             update_player(state, delta_time);
                             handle_player_shooting(state, delta_time);
 
                             update_aliens(state, delta_time);
+                            update_dives(state, delta_time);
                             handle_alien_shooting(state, delta_time);
+                            update_boss(state, delta_time);
 
                             update_mystery_ship(state);
 
@@ -70,28 +87,21 @@ pub fn Game() -> Element {
 
                             check_level_completion(state);
                             check_game_over_conditions(state);
+                        }
+                        update_hud(state, delta_time);
+                        update_starfield(state, delta_time);
+
+                        let level_cleared = state
+                            .events
+                            .iter()
+                            .any(|event| matches!(event, GameEvent::LevelCleared { .. }));
+                        if level_cleared && state.options.adaptive_difficulty {
+                            // The log is only there for designers, so a failed save is not shown
+                            let _ = state.director.save_log();
+                        }
 
-                            if state.game_over {
-                                state.screen = GameScreen::GameOver;
-                            }
-                        } else {
-                            if state.key_states.tab {
-                                *state = GameState {
-                                    high_score: state.high_score,
-                                    lives: 3,
-                                    alien_formation: AlienFormation::new(GAME_WIDTH),
-                                    last_update: instant::Instant::now().elapsed().as_secs_f64(),
-                                    shields: vec![
-                                        Shield::new(100.0, 500.0, ShieldType::UppercaseC),
-                                        Shield::new(250.0, 500.0, ShieldType::UppercaseO),
-                                        Shield::new(400.0, 500.0, ShieldType::UppercaseR),
-                                        Shield::new(550.0, 500.0, ShieldType::UppercaseT),
-                                        Shield::new(700.0, 500.0, ShieldType::UppercaseW),
-                                        Shield::new(850.0, 500.0, ShieldType::UppercaseO),
-                                    ],
-                                    ..Default::default()
-                                };
-                            }
+                        if state.game_over {
+                            finish_game(state);
                         }
                     }
                 });
@@ -102,71 +112,86 @@ pub fn Game() -> Element {
     });
 
     let onkeydown = move |event: KeyboardEvent| {
-        game_state.with_mut(|state| match state.screen {
-            GameScreen::StartScreen => {
-                if event.key() == Key::Enter {
-                    *state = GameState {
-                        high_score: state.high_score,
-                        lives: 3,
-                        level: 1,
-                        screen: GameScreen::Playing,
-                        alien_formation: AlienFormation::new(GAME_WIDTH),
-                        last_update: instant::Instant::now().elapsed().as_secs_f64(),
-                        shields: vec![
-                            Shield::new(100.0, 500.0, ShieldType::UppercaseC),
-                            Shield::new(250.0, 500.0, ShieldType::UppercaseO),
-                            Shield::new(400.0, 500.0, ShieldType::UppercaseR),
-                            Shield::new(550.0, 500.0, ShieldType::UppercaseT),
-                            Shield::new(700.0, 500.0, ShieldType::UppercaseW),
-                            Shield::new(850.0, 500.0, ShieldType::UppercaseO),
-                        ],
-                        mystery_ship_timer: 15.0,
-                        ..Default::default()
-                    };
-                }
-            }
-            GameScreen::Playing => {
-                state.key_states.update_from_key(event.key(), true);
-            }
-            GameScreen::GameOver => {
-                if event.key() == Key::Tab {
-                    *state = GameState {
-                        high_score: state.high_score,
-                        lives: 3,
-                        level: 1,
-                        screen: GameScreen::Playing,
-                        alien_formation: AlienFormation::new(GAME_WIDTH),
-                        last_update: instant::Instant::now().elapsed().as_secs_f64(),
-                        shields: vec![
-                            Shield::new(100.0, 500.0, ShieldType::UppercaseC),
-                            Shield::new(250.0, 500.0, ShieldType::UppercaseO),
-                            Shield::new(400.0, 500.0, ShieldType::UppercaseR),
-                            Shield::new(550.0, 500.0, ShieldType::UppercaseT),
-                            Shield::new(700.0, 500.0, ShieldType::UppercaseW),
-                            Shield::new(850.0, 500.0, ShieldType::UppercaseO),
-                        ],
-                        mystery_ship_timer: 15.0,
-                        ..Default::default()
-                    };
-                }
+        game_state.with_mut(|state| {
+            let key = event.key();
+            if !event.is_auto_repeating() {
+                state.key_states.update(&state.key_bindings, &key, true);
             }
+            state.key_states.type_key(key);
         });
     };
 
     let onkeyup = move |event: KeyboardEvent| {
         game_state.with_mut(|state| {
-            if state.screen == GameScreen::Playing {
-                state.key_states.update_from_key(event.key(), false);
+            state
+                .key_states
+                .update(&state.key_bindings, &event.key(), false)
+        });
+    };
+
+    let on_touch_action = move |(action, pressed): (Action, bool)| {
+        game_state.with_mut(|state| state.key_states.queue(action, pressed));
+    };
+
+    let on_touch_key = move |key: Key| {
+        game_state.with_mut(|state| state.key_states.type_key(key));
+    };
+
+    let onpointermove = move |event: PointerEvent| {
+        game_state.with_mut(|state| {
+            state.key_states.pointer_x = Some(event.element_coordinates().x as f32);
+        });
+    };
+
+    let onpointerdown = move |event: PointerEvent| {
+        game_state.with_mut(|state| {
+            state.key_states.pointer_x = Some(event.element_coordinates().x as f32);
+            state.key_states.queue(Action::Fire, true);
+        });
+    };
+
+    let onpointerup = move |_| {
+        game_state.with_mut(|state| state.key_states.queue(Action::Fire, false));
+    };
+
+    let ontouchstart = move |event: TouchEvent| {
+        if !TOUCH_CONTROLS {
+            return;
+        }
+        game_state.with_mut(|state| {
+            if matches!(
+                state.screen,
+                GameScreen::StartScreen | GameScreen::GameOver | GameScreen::HallOfFame
+            ) {
+                event.prevent_default();
+                state.key_states.queue(Action::Confirm, true);
             }
         });
     };
 
+    let frame_timer = use_hook(|| Rc::new(Cell::new(FrameTimer::default())));
+    let mut timer = frame_timer.get();
+    timer.frame(instant::Instant::now());
+    frame_timer.set(timer);
+
+    let language = game_state.read().settings.language();
+    let theme = game_state.read().settings.theme;
+    let theme_class = theme.css_class();
+    let palette_class = game_state.read().settings.palette.css_class();
+    let motion_class = if game_state.read().settings.reduced_motion {
+        "reduced-motion"
+    } else {
+        ""
+    };
+
     rsx! {
         div {
             id: "game-container",
+            class: "{theme_class} {palette_class} {motion_class}",
             tabindex: "0",
             onkeydown,
             onkeyup,
+            ontouchstart,
             onfocus: move |_| {},
 
             ScoreBoard {
@@ -174,65 +199,192 @@ pub fn Game() -> Element {
                 high_score: game_state.read().high_score,
                 lives: game_state.read().lives,
                 level: game_state.read().level,
+                extra_life_flash: game_state.read().extra_life_flash_timer > 0.0,
+                combo_multiplier: if game_state.read().options.combo_scoring {
+                    Some(game_state.read().combo.multiplier())
+                } else {
+                    None
+                },
+                language,
+            }
+            if game_state.read().settings.show_fps {
+                div { class: "fps-counter",
+                    {
+                        let timer = frame_timer.get();
+                        let frame_ms = if timer.frame_time > 0.0 { format!("{:.1}", timer.frame_time * 1000.0) } else { "-".to_string() };
+                        i18n::format(language, "hud.fps", &[("fps", &timer.fps().round()), ("ms", &frame_ms)])
+                    }
+                }
             }
 
             {
                 match game_state.read().screen {
                     GameScreen::StartScreen => rsx! {
-                        StartScreen {}
+                        StartScreen {
+                            combo_scoring: game_state.read().options.combo_scoring,
+                            arcade_mystery_ship: game_state.read().options.mystery_ship_scoring
+                                == MysteryShipScoring::ShotCount,
+                            difficulty: game_state.read().options.difficulty,
+                            adaptive_difficulty: game_state.read().options.adaptive_difficulty,
+                            pointer_control: game_state.read().options.pointer_control,
+                            key_bindings: game_state.read().key_bindings.clone(),
+                            language,
+                        }
                     },
                     GameScreen::Playing => rsx! {
                         div { id: "game-area",
-                            StarBackground {}
-                            {
-                                if let Some(ship) = &game_state.read().mystery_ship {
-                                    rsx! {
-                                        MysteryShipSprite { ship: ship.clone() }
+                            if game_state.read().settings.starfield {
+                                StarBackground {
+                                    starfield: game_state.read().starfield.clone(),
+                                    reduced_motion: game_state.read().settings.reduced_motion,
+                                }
+                            }
+                            if SVG_RENDERER {
+                                {svg_playfield(&game_state.read())}
+                            } else {
+                                {
+                                    if let Some(ship) = &game_state.read().mystery_ship {
+                                        rsx! {
+                                            MysteryShipSprite { key: "{ship.id}", ship: ship.clone(), theme }
+                                        }
+                                    } else {
+                                        rsx! {
+                                            div {}
+                                        }
                                     }
-                                } else {
-                                    rsx! {
-                                        div {}
+                                }
+                                {
+                                    if let Some(boss) = &game_state.read().boss {
+                                        rsx! {
+                                            BossSprite { boss: boss.clone() }
+                                            BossHealthBar { health: boss.health_fraction() }
+                                        }
+                                    } else {
+                                        rsx! {
+                                            div {}
+                                        }
                                     }
                                 }
+                                {
+                                    game_state.read().shields.iter().map(|shield| rsx! {
+                                        ShieldSprite { key: "{shield.id}", shield: shield.clone() }
+                                    })
+                                }
+                                {
+                                    game_state
+                                        .read()
+                                        .alien_formation
+                                        .aliens
+                                        .iter()
+                                        .map(|alien| rsx! {
+                                            AlienSprite { key: "{alien.id}", view: AlienView::of(alien), theme }
+                                        })
+                                }
+                                {
+                                    game_state
+                                        .read()
+                                        .player_projectiles
+                                        .iter()
+                                        .map(|projectile| rsx! {
+                                            ProjectileSprite { key: "{projectile.id}", projectile: projectile.clone() }
+                                        })
+                                }
+                                {
+                                    game_state
+                                        .read()
+                                        .alien_projectiles
+                                        .iter()
+                                        .map(|projectile| rsx! {
+                                            ProjectileSprite { key: "{projectile.id}", projectile: projectile.clone() }
+                                        })
+                                }
+                                PlayerShip { player: game_state.read().player.clone(), theme }
                             }
-                            {
-                                game_state.read().shields.iter().map(|shield| rsx! {
-                                    ShieldSprite { shield: shield.clone() }
-                                })
+                            if game_state.read().settings.score_popups {
+                                {
+                                    game_state
+                                        .read()
+                                        .score_popups
+                                        .iter()
+                                        .map(|popup| rsx! {
+                                            div {
+                                                class: "score-popup",
+                                                style: "left: {popup.position.x}px; top: {popup.position.y}px; opacity: {popup.timer / SCORE_POPUP_DURATION};",
+                                                "{popup.points}"
+                                            }
+                                        })
+                                }
                             }
-                            {
-                                game_state
-                                    .read()
-                                    .alien_formation
-                                    .aliens
-                                    .iter()
-                                    .map(|alien| rsx! {
-                                        AlienSprite { alien: alien.clone() }
-                                    })
+                            if let Some(transition) = &game_state.read().transition {
+                                LevelTransitionBanner { transition: transition.clone(), language }
                             }
-                            {
-                                game_state
-                                    .read()
-                                    .player_projectiles
-                                    .iter()
-                                    .map(|projectile| rsx! {
-                                        ProjectileSprite { projectile: projectile.clone() }
-                                    })
+                            if game_state.read().paused {
+                                div { class: "pause-overlay",
+                                    {i18n::text(language, "pause.title")}
+                                    p { class: "pause-help", {i18n::text(language, "pause.help")} }
+                                }
                             }
-                            {
-                                game_state
-                                    .read()
-                                    .alien_projectiles
-                                    .iter()
-                                    .map(|projectile| rsx! {
-                                        ProjectileSprite { projectile: projectile.clone() }
-                                    })
+                            if game_state.read().options.pointer_control {
+                                div {
+                                    id: "pointer-surface",
+                                    onpointermove,
+                                    onpointerdown,
+                                    onpointerup,
+                                    onpointerleave: onpointerup,
+                                }
+                            }
+                            if TOUCH_CONTROLS {
+                                TouchControls { on_action: on_touch_action, language }
                             }
-                            PlayerShip { player: game_state.read().player.clone() }
+                        }
+                    },
+                    GameScreen::Settings => rsx! {
+                        SettingsScreen {
+                            settings: game_state.read().settings.clone(),
+                            menu: game_state.read().settings_menu.clone(),
+                        }
+                    },
+                    GameScreen::Controls => rsx! {
+                        ControlsScreen {
+                            bindings: game_state.read().key_bindings.clone(),
+                            menu: game_state.read().controls.clone(),
+                            language,
+                        }
+                    },
+                    GameScreen::LevelEditor => rsx! {
+                        LevelEditorScreen {
+                            editor: game_state.read().editor.clone().unwrap_or_default(),
+                            language,
+                            theme,
+                        }
+                    },
+                    GameScreen::InitialsEntry => rsx! {
+                        InitialsEntryScreen {
+                            score: game_state.read().score,
+                            initials: game_state.read().initials.clone(),
+                            language,
+                        }
+                        if TOUCH_CONTROLS {
+                            InitialsTouchControls { on_key: on_touch_key, language }
+                        }
+                    },
+                    GameScreen::HallOfFame => rsx! {
+                        HallOfFameScreen {
+                            entries: game_state.read().leaderboard.entries.clone(),
+                            highlight: game_state.read().hall_of_fame_rank,
+                            key_bindings: game_state.read().key_bindings.clone(),
+                            language,
                         }
                     },
                     GameScreen::GameOver => rsx! {
-                        GameOverScreen { score: game_state.read().score, high_score: game_state.read().high_score }
+                        GameOverScreen {
+                            score: game_state.read().score,
+                            high_score: game_state.read().high_score,
+                            difficulty: game_state.read().options.difficulty,
+                            leaderboard: game_state.read().leaderboard.entries.clone(),
+                            key_bindings: game_state.read().key_bindings.clone(),
+                            language,
+                        }
                     },
                 }
             }
@@ -240,9 +392,283 @@ pub fn Game() -> Element {
     }
 }
 
+/// Hands the keys typed since the previous tick to the screen that is showing
+/// Actions that start, pause or leave a game are handled by `handle_input`.
+/// # Arguments
+/// * `state` - Mutable reference to the current game state
+fn handle_typed_keys(state: &mut GameState) {
+    for key in std::mem::take(&mut state.key_states.just_typed) {
+        match state.screen {
+            GameScreen::StartScreen => handle_start_key(state, key),
+            GameScreen::Playing
+                if state.paused
+                    && matches!(key, Key::Character(ref c) if c.eq_ignore_ascii_case("s")) =>
+            {
+                open_settings(state, GameScreen::Playing);
+            }
+            GameScreen::InitialsEntry => handle_initials_key(state, key),
+            GameScreen::LevelEditor => handle_editor_key(state, key),
+            GameScreen::Controls => handle_controls_key(state, key),
+            GameScreen::Settings => handle_settings_key(state, key),
+            _ => {}
+        }
+    }
+}
+
+/// Handles a key press on the start screen
+/// The option hotkeys come first, so a letter bound to an action still toggles
+/// its option; the Move Left and Move Right keys change the difficulty.
+/// # Arguments
+/// * `state` - Mutable reference to the current game state
+/// * `key` - The key that was pressed
+fn handle_start_key(state: &mut GameState, key: Key) {
+    match key {
+        Key::Character(c) if c.eq_ignore_ascii_case("s") => {
+            open_settings(state, GameScreen::StartScreen);
+        }
+        Key::Character(c) if c.eq_ignore_ascii_case("a") => {
+            state.options.adaptive_difficulty = !state.options.adaptive_difficulty;
+        }
+        Key::Character(c) if c.eq_ignore_ascii_case("c") => {
+            state.options.combo_scoring = !state.options.combo_scoring;
+        }
+        Key::Character(c) if c.eq_ignore_ascii_case("m") => {
+            state.options.mystery_ship_scoring = match state.options.mystery_ship_scoring {
+                MysteryShipScoring::Random => MysteryShipScoring::ShotCount,
+                MysteryShipScoring::ShotCount => MysteryShipScoring::Random,
+            };
+        }
+        Key::Character(c) if c.eq_ignore_ascii_case("o") => {
+            state.options.pointer_control = !state.options.pointer_control;
+        }
+        Key::Character(c) if c.eq_ignore_ascii_case("k") => {
+            state.controls = ControlsMenu::default();
+            state.screen = GameScreen::Controls;
+        }
+        Key::Character(c) if c.eq_ignore_ascii_case("e") => {
+            state.editor.get_or_insert_with(LevelEditor::default);
+            state.screen = GameScreen::LevelEditor;
+        }
+        Key::Character(c) if c.eq_ignore_ascii_case("l") => {
+            let language = state.settings.language();
+            match load_custom_level(language).and_then(|level| {
+                state
+                    .start_custom_level(&level, false)
+                    .map_err(|error| error.message(language))
+            }) {
+                Ok(custom) => *state = custom,
+                Err(error) => {
+                    let message =
+                        i18n::format(language, "editor.load_failed", &[("error", &error)]);
+                    state
+                        .editor
+                        .get_or_insert_with(LevelEditor::default)
+                        .message = Some(message);
+                    state.screen = GameScreen::LevelEditor;
+                }
+            }
+        }
+        _ if state.key_bindings.is(&key, Action::MoveLeft) => {
+            state.options.difficulty = state.options.difficulty.previous();
+            state.settings.difficulty = state.options.difficulty;
+        }
+        _ if state.key_bindings.is(&key, Action::MoveRight) => {
+            state.options.difficulty = state.options.difficulty.next();
+            state.settings.difficulty = state.options.difficulty;
+        }
+        _ => {}
+    }
+}
+
+/// Handles a key press on the level editor screen
+/// The editor's own hotkeys come first; the Move Left and Move Right keys move
+/// the cursor, Fire and Confirm place the selected tool and Back leaves.
+/// # Arguments
+/// * `state` - Mutable reference to the current game state
+/// * `key` - The key that was pressed
+fn handle_editor_key(state: &mut GameState, key: Key) {
+    let language = state.settings.language();
+    let action = state.key_bindings.action_for(&key);
+    let editor = state.editor.get_or_insert_with(LevelEditor::default);
+    editor.message = None;
+
+    match (key_name(&key).as_str(), action) {
+        ("1", _) => editor.select_tool(EditorTool::Alien(AlienType::Small)),
+        ("2", _) => editor.select_tool(EditorTool::Alien(AlienType::Medium)),
+        ("3", _) => editor.select_tool(EditorTool::Alien(AlienType::Large)),
+        ("4", _) => editor.select_tool(EditorTool::Shield(ShieldType::UppercaseC)),
+        ("x", _) => editor.select_tool(EditorTool::Erase),
+        ("+" | "=", _) => editor.adjust_speed(5.0),
+        ("-", _) => editor.adjust_speed(-5.0),
+        ("]", _) => editor.adjust_fire_rate(0.25),
+        ("[", _) => editor.adjust_fire_rate(-0.25),
+        ("s", _) => editor.save(language),
+        ("l", _) => editor.load(language),
+        ("p", _) => {
+            let level = editor.level.clone();
+            match state.start_custom_level(&level, true) {
+                Ok(playtest) => *state = playtest,
+                Err(error) => {
+                    state
+                        .editor
+                        .get_or_insert_with(LevelEditor::default)
+                        .message = Some(error.message(language));
+                }
+            }
+        }
+        ("ArrowUp", _) => editor.move_cursor(0, -1),
+        ("ArrowDown", _) => editor.move_cursor(0, 1),
+        (_, Some(Action::MoveLeft)) => editor.move_cursor(-1, 0),
+        (_, Some(Action::MoveRight)) => editor.move_cursor(1, 0),
+        (_, Some(Action::Fire | Action::Confirm)) => editor.apply(language),
+        (_, Some(Action::Back)) => state.screen = GameScreen::StartScreen,
+        _ => {}
+    }
+}
+
+/// Handles a key press on the Controls screen
+/// The Confirm and Back keys work here, but so do Enter and Escape whatever
+/// they are bound to, so that a bad binding can never lock the player out of it.
+/// # Arguments
+/// * `state` - Mutable reference to the current game state
+/// * `key` - The key that was pressed
+fn handle_controls_key(state: &mut GameState, key: Key) {
+    let language = state.settings.language();
+    let menu = &mut state.controls;
+    let bindings = &mut state.key_bindings;
+    let previous_message = menu.message.take();
+
+    if menu.capturing {
+        menu.capturing = false;
+        if key != Key::Escape {
+            bindings.bind(menu.action(), &key);
+        }
+        return;
+    }
+
+    match (&key, bindings.action_for(&key)) {
+        (Key::ArrowUp, _) => menu.move_selection(-1),
+        (Key::ArrowDown, _) => menu.move_selection(1),
+        (Key::Backspace | Key::Delete, _) => bindings.clear(menu.action()),
+        (Key::Character(c), _) if c.eq_ignore_ascii_case("r") => *bindings = KeyBindings::default(),
+        (Key::Enter, _) | (_, Some(Action::Confirm)) => menu.capturing = true,
+        (Key::Escape, _) | (_, Some(Action::Back)) => {
+            bindings.fill_missing();
+            match bindings.save() {
+                Err(error) if previous_message.is_none() => {
+                    menu.message = Some(i18n::format(
+                        language,
+                        "controls.save_failed",
+                        &[("error", &error)],
+                    ));
+                }
+                _ => state.screen = menu.return_to,
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Handles a key press on the Settings screen
+/// Leaving the screen with Back saves the settings. The difficulty can only be
+/// changed from the title screen, not in the middle of a game.
+/// # Arguments
+/// * `state` - Mutable reference to the current game state
+/// * `key` - The key that was pressed
+fn handle_settings_key(state: &mut GameState, key: Key) {
+    let menu = &mut state.settings_menu;
+    let previous_message = menu.message.take();
+    let row = menu.row();
+    let action = state.key_bindings.action_for(&key);
+    let delta = match action {
+        Some(Action::MoveLeft) => -1,
+        Some(Action::MoveRight | Action::Confirm) => 1,
+        _ => 0,
+    };
+
+    match key {
+        Key::ArrowUp => menu.move_selection(-1),
+        Key::ArrowDown => menu.move_selection(1),
+        _ if action == Some(Action::Confirm) && row == SettingsRow::Controls => {
+            state.controls = ControlsMenu {
+                return_to: GameScreen::Settings,
+                ..Default::default()
+            };
+            state.screen = GameScreen::Controls;
+        }
+        _ if delta != 0 && row == SettingsRow::Difficulty => {
+            if menu.return_to == GameScreen::StartScreen {
+                state.settings.adjust(row, delta);
+                state.options.difficulty = state.settings.difficulty;
+            } else {
+                menu.message = Some(i18n::text(
+                    state.settings.language(),
+                    "settings.difficulty_locked",
+                ));
+            }
+        }
+        _ if delta != 0 => state.settings.adjust(row, delta),
+        _ if action == Some(Action::Back) => match state.settings.save() {
+            Err(error) if previous_message.is_none() => {
+                menu.message = Some(i18n::format(
+                    state.settings.language(),
+                    "settings.save_failed",
+                    &[("error", &error)],
+                ));
+            }
+            _ => state.screen = menu.return_to,
+        },
+        _ => {}
+    }
+}
+
+/// Handles a key press on the initials entry screen
+/// Letters are typed in, even when they are bound to an action. Up and Down
+/// change the letter, Move Left and Move Right pick which letter to change, and
+/// Enter or Confirm moves on, submitting the initials on the last letter. The
+/// touch buttons type the same keys.
+/// # Arguments
+/// * `state` - Mutable reference to the current game state
+/// * `key` - The key that was pressed
+fn handle_initials_key(state: &mut GameState, key: Key) {
+    let initials = &mut state.initials;
+
+    match (&key, state.key_bindings.action_for(&key)) {
+        (Key::Character(c), _) if c.chars().all(|c| c.is_ascii_alphabetic()) => {
+            if let Some(letter) = c.chars().next() {
+                initials.type_letter(letter);
+            }
+        }
+        (Key::ArrowUp, _) => initials.change_letter(1),
+        (Key::ArrowDown, _) => initials.change_letter(-1),
+        (Key::Backspace, _) | (_, Some(Action::MoveLeft)) => initials.move_cursor(-1),
+        (_, Some(Action::MoveRight)) => initials.move_cursor(1),
+        (Key::Enter, _) | (_, Some(Action::Confirm)) if initials.on_last_letter() => {
+            submit_initials(state)
+        }
+        (Key::Enter, _) | (_, Some(Action::Confirm)) => initials.move_cursor(1),
+        _ => {}
+    }
+}
+
+/// Opens the Settings screen
+/// # Arguments
+/// * `state` - Mutable reference to the current game state
+/// * `return_to` - The screen to go back to when the player leaves
+fn open_settings(state: &mut GameState, return_to: GameScreen) {
+    state.settings_menu = SettingsMenu {
+        return_to,
+        ..Default::default()
+    };
+    state.screen = GameScreen::Settings;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::game::difficulty::Difficulty;
+    use crate::game::entities::alien::AlienFormation;
+    use crate::game::entities::shield::{Shield, ShieldType};
 
     #[test]
     fn test_game_initialization() {
@@ -298,6 +724,176 @@ mod tests {
         assert_eq!(game_state.shields.len(), 6);
     }
 
+    // Test that the editor keys edit the level and P starts a playtest
+    #[test]
+    fn test_editor_keys() {
+        let mut game_state = GameState {
+            screen: GameScreen::LevelEditor,
+            ..Default::default()
+        };
+
+        handle_editor_key(&mut game_state, Key::Character("3".to_string()));
+        handle_editor_key(&mut game_state, Key::ArrowDown);
+        handle_editor_key(&mut game_state, Key::Character("+".to_string()));
+
+        let editor = game_state.editor.as_ref().unwrap();
+        assert_eq!(editor.tool, EditorTool::Alien(AlienType::Large));
+        assert_eq!(editor.cursor_row, 1);
+        assert_eq!(editor.level.layout.formation_speed, 25.0);
+
+        handle_editor_key(&mut game_state, Key::Character("p".to_string()));
+        assert_eq!(game_state.screen, GameScreen::Playing);
+        assert!(game_state.playtesting);
+        assert!(game_state.editor.is_some());
+
+        handle_editor_key(&mut game_state, Key::Escape);
+        assert_eq!(game_state.screen, GameScreen::StartScreen);
+    }
+
+    // Test that initials are entered with the arrows and lead to the Hall of Fame
+    #[test]
+    fn test_initials_keys() {
+        let mut game_state = GameState {
+            screen: GameScreen::InitialsEntry,
+            score: 800,
+            ..Default::default()
+        };
+
+        handle_initials_key(&mut game_state, Key::ArrowDown);
+        handle_initials_key(&mut game_state, Key::Enter);
+        handle_initials_key(&mut game_state, Key::ArrowUp);
+        handle_initials_key(&mut game_state, Key::ArrowRight);
+        assert_eq!(game_state.initials.text(), "ZBA");
+        assert_eq!(game_state.screen, GameScreen::InitialsEntry);
+
+        game_state.key_bindings.clear(Action::Confirm);
+        handle_initials_key(&mut game_state, Key::Enter);
+        assert_eq!(game_state.screen, GameScreen::HallOfFame);
+        assert_eq!(game_state.leaderboard.entries[0].initials, "ZBA");
+        assert_eq!(game_state.hall_of_fame_rank, Some(0));
+    }
+
+    // Test that the Settings screen changes values and opens the Controls screen
+    #[test]
+    fn test_settings_keys() {
+        let mut game_state = GameState::default();
+        open_settings(&mut game_state, GameScreen::StartScreen);
+
+        handle_settings_key(&mut game_state, Key::ArrowLeft);
+        assert_eq!(game_state.settings.difficulty, Difficulty::Easy);
+        assert_eq!(game_state.options.difficulty, Difficulty::Easy);
+
+        handle_settings_key(&mut game_state, Key::ArrowDown);
+        handle_settings_key(&mut game_state, Key::Enter);
+        assert_eq!(game_state.screen, GameScreen::Controls);
+        assert_eq!(game_state.controls.return_to, GameScreen::Settings);
+
+        game_state.screen = GameScreen::Settings;
+        handle_settings_key(&mut game_state, Key::ArrowDown);
+        handle_settings_key(&mut game_state, Key::ArrowLeft);
+        assert_eq!(game_state.settings.volume, 6);
+    }
+
+    // Test that the difficulty cannot be changed from the pause menu
+    #[test]
+    fn test_settings_from_pause_menu() {
+        let mut game_state = GameState {
+            screen: GameScreen::Playing,
+            paused: true,
+            ..Default::default()
+        };
+        open_settings(&mut game_state, GameScreen::Playing);
+
+        handle_settings_key(&mut game_state, Key::ArrowRight);
+        assert_eq!(game_state.options.difficulty, Difficulty::Normal);
+        assert!(game_state.settings_menu.message.is_some());
+    }
+
+    // Test that typed keys reach the screen on the next tick and follow the bindings
+    #[test]
+    fn test_typed_keys_follow_bindings() {
+        let mut game_state = GameState::default();
+        game_state
+            .key_bindings
+            .bind(Action::MoveLeft, &Key::Character("j".to_string()));
+
+        game_state
+            .key_states
+            .type_key(Key::Character("j".to_string()));
+        handle_typed_keys(&mut game_state);
+        assert_eq!(game_state.options.difficulty, Difficulty::Normal);
+
+        game_state.key_states.begin_tick();
+        handle_typed_keys(&mut game_state);
+        assert_eq!(game_state.options.difficulty, Difficulty::Easy);
+
+        game_state
+            .key_states
+            .type_key(Key::Character("S".to_string()));
+        game_state.key_states.begin_tick();
+        handle_typed_keys(&mut game_state);
+        assert_eq!(game_state.screen, GameScreen::Settings);
+    }
+
+    // Test that a key that leaves a playtest is not handled again by the editor in the same tick
+    #[test]
+    fn test_playtest_keys_handled_once() {
+        let mut game_state = GameState {
+            screen: GameScreen::Playing,
+            playtesting: true,
+            editor: Some(Default::default()),
+            ..Default::default()
+        };
+
+        game_state
+            .key_states
+            .update(&game_state.key_bindings.clone(), &Key::Escape, true);
+        game_state.key_states.type_key(Key::Escape);
+        handle_input(&mut game_state);
+        handle_typed_keys(&mut game_state);
+        assert_eq!(game_state.screen, GameScreen::LevelEditor);
+
+        game_state.screen = GameScreen::GameOver;
+        let editor = game_state.editor.clone();
+        game_state
+            .key_states
+            .update(&game_state.key_bindings.clone(), &Key::Enter, true);
+        game_state.key_states.type_key(Key::Enter);
+        handle_input(&mut game_state);
+        handle_typed_keys(&mut game_state);
+        assert_eq!(game_state.screen, GameScreen::LevelEditor);
+        assert_eq!(game_state.editor, editor);
+    }
+
+    // Test that the Controls screen rebinds the highlighted action
+    #[test]
+    fn test_controls_keys() {
+        let mut game_state = GameState {
+            screen: GameScreen::Controls,
+            ..Default::default()
+        };
+
+        handle_controls_key(&mut game_state, Key::ArrowDown);
+        handle_controls_key(&mut game_state, Key::Backspace);
+        assert!(game_state.key_bindings.move_right.is_empty());
+
+        handle_controls_key(&mut game_state, Key::Enter);
+        assert!(game_state.controls.capturing);
+        handle_controls_key(&mut game_state, Key::Character("L".to_string()));
+
+        assert!(!game_state.controls.capturing);
+        assert_eq!(game_state.key_bindings.move_right, vec!["l".to_string()]);
+        assert_eq!(
+            game_state
+                .key_bindings
+                .action_for(&Key::Character("l".to_string())),
+            Some(Action::MoveRight)
+        );
+
+        handle_controls_key(&mut game_state, Key::Character("r".to_string()));
+        assert_eq!(game_state.key_bindings, KeyBindings::default());
+    }
+
     // Test the key handling logic
     #[test]
     fn test_key_handling() {
@@ -329,14 +925,22 @@ fn test_key_state_updates() {
         screen: GameScreen::Playing,
         ..Default::default()
     };
+    let bindings = KeyBindings::default();
 
-    game_state.key_states.update_from_key(Key::ArrowLeft, true);
+    game_state
+        .key_states
+        .update(&bindings, &Key::ArrowLeft, true);
+    game_state.key_states.begin_tick();
     assert!(game_state.key_states.left);
 
-    game_state.key_states.update_from_key(Key::Shift, true);
+    game_state.key_states.update(&bindings, &Key::Shift, true);
+    game_state.key_states.begin_tick();
     assert!(game_state.key_states.shift);
 
-    game_state.key_states.update_from_key(Key::ArrowLeft, false);
+    game_state
+        .key_states
+        .update(&bindings, &Key::ArrowLeft, false);
+    game_state.key_states.begin_tick();
     assert!(!game_state.key_states.left);
 }
 
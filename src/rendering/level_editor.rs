@@ -0,0 +1,117 @@
+use crate::game::editor::{shield_letter, shield_x, LevelEditor, EDITOR_ROWS, SHIELD_COLUMNS};
+use crate::game::entities::alien::{Alien, AlienFormation, FORMATION_COLS};
+use crate::game::level::SHIELD_Y;
+use crate::game::settings::Theme;
+use crate::rendering::sprites::alien_sprite::{AlienSprite, AlienView};
+use crate::rendering::sprites::shield_sprite::ShieldSprite;
+use crate::utils::i18n::{self, Language};
+use dioxus::prelude::*;
+
+/// Displays the level editor: the alien grid, the shield row and a panel
+/// with the level settings and editor controls
+#[derive(Props, Clone, PartialEq, Debug)]
+pub struct LevelEditorScreenProps {
+    pub editor: LevelEditor,
+    pub language: Language,
+    /// The theme whose art the aliens are drawn with
+    pub theme: Theme,
+}
+
+#[component]
+#[allow(non_snake_case)]
+pub fn LevelEditorScreen(props: LevelEditorScreenProps) -> Element {
+    let editor = &props.editor;
+    let layout = &editor.level.layout;
+    let cursor = editor.cursor_position();
+    let (cursor_width, cursor_height) = if editor.on_shield_row() {
+        (100.0, 50.0)
+    } else {
+        (50.0, 50.0)
+    };
+    let language = props.language;
+    let theme = props.theme;
+    let tool = editor.tool.label(language);
+    let shield_letters: String = layout
+        .shields
+        .iter()
+        .map(|s| shield_letter(s.shield_type))
+        .collect();
+
+    let cells = (0..EDITOR_ROWS).flat_map(|row| {
+        (0..FORMATION_COLS).map(move |column| {
+            let position = AlienFormation::slot_position(column, row);
+            rsx! {
+                div {
+                    class: "editor-cell",
+                    style: "left: {position.x}px; top: {position.y}px;",
+                }
+            }
+        })
+    });
+
+    let shield_cells = (0..SHIELD_COLUMNS).map(|column| {
+        let x = shield_x(column);
+        rsx! {
+            div {
+                class: "editor-cell editor-shield-cell",
+                style: "left: {x}px; top: {SHIELD_Y}px;",
+            }
+        }
+    });
+
+    let aliens = (0..EDITOR_ROWS).flat_map(|row| {
+        (0..FORMATION_COLS).filter_map(move |column| {
+            let placement = editor.alien_at(column, row)?;
+            let position = AlienFormation::slot_position(column, row);
+            let alien = Alien::new(position.x, position.y, placement.alien_type);
+            Some(rsx! {
+                AlienSprite { key: "{column}-{row}", view: AlienView::of(&alien), theme }
+            })
+        })
+    });
+
+    let shields = layout.build_shields().into_iter().map(|shield| {
+        rsx! {
+            ShieldSprite { key: "{shield.position.x}", shield }
+        }
+    });
+
+    rsx! {
+        div { id: "level-editor",
+            div { id: "game-area",
+                {cells}
+                {shield_cells}
+                {aliens}
+                {shields}
+                div {
+                    class: "editor-cursor",
+                    style: "left: {cursor.x}px; top: {cursor.y}px; width: {cursor_width}px; height: {cursor_height}px;",
+                }
+            }
+            div { class: "editor-panel",
+                div { class: "editor-title", {i18n::text(language, "editor.title")} }
+                p { {i18n::format(language, "editor.tool", &[("tool", &tool)])} }
+                p {
+                    {
+                        i18n::format(
+                            language,
+                            "editor.counts",
+                            &[("aliens", &layout.aliens.len()), ("shields", &shield_letters)],
+                        )
+                    }
+                }
+                p { {i18n::format(language, "editor.speed", &[("speed", &layout.formation_speed)])} }
+                p { {i18n::format(language, "editor.fire_rate", &[("rate", &layout.fire_rate)])} }
+                if let Some(message) = &editor.message {
+                    p { class: "editor-message", "{message}" }
+                }
+                div { class: "editor-help",
+                    p { {i18n::text(language, "editor.help_move")} }
+                    p { {i18n::text(language, "editor.help_tools")} }
+                    p { {i18n::text(language, "editor.help_edit")} }
+                    p { {i18n::text(language, "editor.help_keys")} }
+                }
+            }
+        }
+    }
+}
@@ -1,5 +1,11 @@
+pub mod controls_screen;
+pub mod frame_timer;
 pub mod game;
+pub mod level_editor;
 pub mod score_board;
 pub mod screens;
+pub mod settings_screen;
 pub mod sprites;
 pub mod star_background;
+pub mod svg_playfield;
+pub mod touch_controls;
@@ -1,3 +1,4 @@
+use crate::utils::i18n::{self, Language};
 use dioxus::prelude::*;
 
 /// A component that displays the game's score information
@@ -9,18 +10,38 @@ pub struct ScoreBoardProps {
     pub high_score: i32,
     pub lives: i32,
     pub level: i32,
+    pub extra_life_flash: bool,
+    pub combo_multiplier: Option<i32>,
+    pub language: Language,
 }
 
 /// Renders the game's score information
 #[component]
 #[allow(non_snake_case)]
 pub fn ScoreBoard(props: ScoreBoardProps) -> Element {
+    let lives_class = if props.extra_life_flash {
+        "lives extra-life-flash"
+    } else {
+        "lives"
+    };
+
+    let language = props.language;
+
     rsx! {
         div { id: "score-board",
-            div { class: "score", "Score: {props.score}" }
-            div { class: "lives", "Lives: {props.lives}" }
-            div { class: "high-score", "High Score: {props.high_score}" }
-            div { class: "level", "Level: {props.level}" }
+            div { class: "score", {i18n::format(language, "hud.score", &[("score", &props.score)])} }
+            div { class: "{lives_class}",
+                {i18n::format(language, "hud.lives", &[("lives", &props.lives)])}
+            }
+            div { class: "high-score",
+                {i18n::format(language, "hud.high_score", &[("score", &props.high_score)])}
+            }
+            div { class: "level", {i18n::format(language, "hud.level", &[("level", &props.level)])} }
+            if let Some(multiplier) = props.combo_multiplier {
+                div { class: if multiplier > 1 { "combo combo-active" } else { "combo" },
+                    {i18n::format(language, "hud.combo", &[("multiplier", &multiplier)])}
+                }
+            }
         }
     }
 }
@@ -37,6 +58,9 @@ mod tests {
             high_score: 500,
             lives: 3,
             level: 1,
+            extra_life_flash: false,
+            combo_multiplier: None,
+            language: Language::English,
         };
 
         let props2 = ScoreBoardProps {
@@ -44,6 +68,9 @@ mod tests {
             high_score: 500,
             lives: 3,
             level: 1,
+            extra_life_flash: false,
+            combo_multiplier: None,
+            language: Language::English,
         };
 
         let props3 = ScoreBoardProps {
@@ -51,6 +78,9 @@ mod tests {
             high_score: 500,
             lives: 3,
             level: 1,
+            extra_life_flash: false,
+            combo_multiplier: None,
+            language: Language::English,
         };
 
         assert_eq!(props1, props2);
@@ -65,6 +95,9 @@ mod tests {
             high_score: 500,
             lives: 3,
             level: 1,
+            extra_life_flash: false,
+            combo_multiplier: None,
+            language: Language::English,
         };
 
         let cloned_props = props.clone();
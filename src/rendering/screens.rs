@@ -1,23 +1,96 @@
+use crate::game::difficulty::Difficulty;
+use crate::game::leaderboard::{InitialsEntry, LeaderboardEntry};
+use crate::game::transition::{LevelTransition, TransitionPhase};
+use crate::input::bindings::{Action, KeyBindings};
+use crate::utils::i18n::{self, Language};
 use dioxus::prelude::*;
 
 /// Displays the initial game screen with title and instructions
 /// This component renders the start screen that appears when the game first loads,
-/// showing the game title, instructions, and a prompt to start the game.
+/// showing the game title, instructions, the selectable options and a prompt to start the game.
+#[derive(Props, Clone, PartialEq, Debug)]
+pub struct StartScreenProps {
+    pub combo_scoring: bool,
+    pub arcade_mystery_ship: bool,
+    pub difficulty: Difficulty,
+    pub adaptive_difficulty: bool,
+    pub pointer_control: bool,
+    pub key_bindings: KeyBindings,
+    pub language: Language,
+}
 
 #[component]
 #[allow(non_snake_case)]
-pub fn StartScreen() -> Element {
+pub fn StartScreen(props: StartScreenProps) -> Element {
+    let language = props.language;
+    let text = |key: &str| i18n::text(language, key);
+    let on_off = |on: bool| i18n::on_off(language, on);
+    let difficulty = props.difficulty.label(language);
+    let preset = props.difficulty.preset();
+    let mystery_label = text(if props.arcade_mystery_ship {
+        "start.mystery_arcade"
+    } else {
+        "start.mystery_random"
+    });
+    let keys = |action: Action| props.key_bindings.label(action, language);
+
     rsx! {
         div { id: "start-screen",
-            div { class: "title", "SPACE INVADERS" }
-            div { class: "subtitle", "CORTWO EDITION" }
+            div { class: "title", {text("start.title")} }
+            div { class: "subtitle", {text("start.subtitle")} }
             div { class: "instructions",
-                p { "Use LEFT and RIGHT arrow keys to move" }
-                p { "Press SHIFT to fire" }
-                p { "Destroy all aliens to advance to the next level" }
-                p { "Protect your ship and don't let aliens reach the bottom" }
+                p {
+                    {
+                        i18n::format(
+                            language,
+                            "start.move",
+                            &[("left", &keys(Action::MoveLeft)), ("right", &keys(Action::MoveRight))],
+                        )
+                    }
+                }
+                p {
+                    {
+                        i18n::format(
+                            language,
+                            "start.fire",
+                            &[("fire", &keys(Action::Fire)), ("pause", &keys(Action::Pause))],
+                        )
+                    }
+                }
+                p { {text("start.goal")} }
+                p { {text("start.protect")} }
+            }
+            div { class: "difficulty-selector",
+                span { class: "difficulty-arrow", "<" }
+                span { class: "difficulty-name", "{difficulty}" }
+                span { class: "difficulty-arrow", ">" }
+            }
+            div { class: "difficulty-summary",
+                {i18n::format(language, "start.lives", &[("lives", &preset.starting_lives)])}
+                if !preset.invasion_ends_game {
+                    {text("start.lenient")}
+                }
+            }
+            div { class: "options",
+                p { {text("start.choose_difficulty")} }
+                p {
+                    {
+                        i18n::format(
+                            language,
+                            "start.adaptive",
+                            &[("value", &on_off(props.adaptive_difficulty))],
+                        )
+                    }
+                }
+                p { {i18n::format(language, "start.combo", &[("value", &on_off(props.combo_scoring))])} }
+                p { {i18n::format(language, "start.mystery", &[("value", &mystery_label)])} }
+                p { {i18n::format(language, "start.pointer", &[("value", &on_off(props.pointer_control))])} }
+                p { {text("start.editor")} }
+                p { {text("start.settings")} }
+            }
+            div { class: "start-prompt",
+                {i18n::format(language, "start.prompt", &[("confirm", &keys(Action::Confirm))])}
             }
-            div { class: "start-prompt", "Press ENTER to start" }
         }
     }
 }
@@ -29,22 +102,184 @@ pub fn StartScreen() -> Element {
 pub struct GameOverScreenProps {
     pub score: i32,
     pub high_score: i32,
+    pub difficulty: Difficulty,
+    pub leaderboard: Vec<LeaderboardEntry>,
+    pub key_bindings: KeyBindings,
+    pub language: Language,
 }
 
 /// Displays the game over screen with final score and restart prompt
 #[component]
 #[allow(non_snake_case)]
 pub fn GameOverScreen(props: GameOverScreenProps) -> Element {
+    let language = props.language;
+    let confirm = props.key_bindings.label(Action::Confirm, language);
+
     rsx! {
         div { id: "game-over-screen",
-            div { class: "game-over-title", "GAME OVER" }
-            div { class: "final-score", "Your Score: {props.score}" }
-            div { class: "high-score", "High Score: {props.high_score}" }
-            div { class: "restart-prompt", "Press TAB to play again" }
+            div { class: "game-over-title", {i18n::text(language, "game_over.title")} }
+            div { class: "final-score",
+                {i18n::format(language, "game_over.your_score", &[("score", &props.score)])}
+            }
+            div { class: "high-score",
+                {i18n::format(language, "game_over.high_score", &[("score", &props.high_score)])}
+            }
+            div { class: "final-difficulty",
+                {
+                    i18n::format(
+                        language,
+                        "game_over.difficulty",
+                        &[("difficulty", &props.difficulty.label(language))],
+                    )
+                }
+            }
+            if !props.leaderboard.is_empty() {
+                table { class: "leaderboard",
+                    {leaderboard_rows(&props.leaderboard[..props.leaderboard.len().min(5)], None, language)}
+                }
+            }
+            div { class: "restart-prompt",
+                {i18n::format(language, "game_over.prompt", &[("confirm", &confirm)])}
+            }
         }
     }
 }
 
+/// Renders leaderboard entries as table rows
+/// # Arguments
+/// * `entries` - The entries to show, best first
+/// * `highlight` - The rank of a row to highlight, if any
+/// * `language` - The language the level and difficulty are shown in
+/// # Returns -> The rows
+fn leaderboard_rows(
+    entries: &[LeaderboardEntry],
+    highlight: Option<usize>,
+    language: Language,
+) -> Element {
+    rsx! {
+        for (rank, entry) in entries.iter().enumerate() {
+            tr { class: if Some(rank) == highlight { "new-entry" } else { "" },
+                td { "{rank + 1}." }
+                td { "{entry.initials}" }
+                td { "{entry.score}" }
+                td { {i18n::format(language, "game_over.level", &[("level", &entry.level)])} }
+                td { {entry.difficulty.label(language)} }
+            }
+        }
+    }
+}
+
+/// Displays the arcade-style initials entry after a score makes the leaderboard
+#[derive(Props, Clone, PartialEq, Debug)]
+pub struct InitialsEntryScreenProps {
+    pub score: i32,
+    pub initials: InitialsEntry,
+    pub language: Language,
+}
+
+#[component]
+#[allow(non_snake_case)]
+pub fn InitialsEntryScreen(props: InitialsEntryScreenProps) -> Element {
+    let language = props.language;
+
+    rsx! {
+        div { id: "initials-screen",
+            div { class: "game-over-title", {i18n::text(language, "initials.title")} }
+            div { class: "final-score",
+                {i18n::format(language, "game_over.your_score", &[("score", &props.score)])}
+            }
+            div { class: "initials",
+                for (index, letter) in props.initials.letters.iter().enumerate() {
+                    span { class: if index == props.initials.cursor { "initial selected" } else { "initial" },
+                        "{letter}"
+                    }
+                }
+            }
+            div { class: "instructions",
+                p { {i18n::text(language, "initials.help_letters")} }
+                p { {i18n::text(language, "initials.help_confirm")} }
+            }
+        }
+    }
+}
+
+/// Displays the top ten scores, highlighting the one that was just added
+#[derive(Props, Clone, PartialEq, Debug)]
+pub struct HallOfFameScreenProps {
+    pub entries: Vec<LeaderboardEntry>,
+    /// The rank of the newly added entry, if any
+    pub highlight: Option<usize>,
+    pub key_bindings: KeyBindings,
+    pub language: Language,
+}
+
+#[component]
+#[allow(non_snake_case)]
+pub fn HallOfFameScreen(props: HallOfFameScreenProps) -> Element {
+    let language = props.language;
+    let confirm = props.key_bindings.label(Action::Confirm, language);
+    let back = props.key_bindings.label(Action::Back, language);
+
+    rsx! {
+        div { id: "hall-of-fame-screen",
+            div { class: "game-over-title", {i18n::text(language, "hall_of_fame.title")} }
+            table { class: "leaderboard hall-of-fame",
+                {leaderboard_rows(&props.entries, props.highlight, language)}
+            }
+            div { class: "restart-prompt",
+                {
+                    i18n::format(
+                        language,
+                        "hall_of_fame.prompt",
+                        &[("confirm", &confirm), ("back", &back)],
+                    )
+                }
+            }
+        }
+    }
+}
+
+/// Displays the level transition over the playfield: the tally of the cleared
+/// wave, then the banner of the next one while it slides in
+#[derive(Props, Clone, PartialEq, Debug)]
+pub struct LevelTransitionBannerProps {
+    pub transition: LevelTransition,
+    pub language: Language,
+}
+
+#[component]
+#[allow(non_snake_case)]
+pub fn LevelTransitionBanner(props: LevelTransitionBannerProps) -> Element {
+    let language = props.language;
+    let transition = &props.transition;
+
+    match transition.phase() {
+        TransitionPhase::Tally => {
+            let percent = format!("{:.0}", transition.stats.accuracy() * 100.0);
+            let seconds = format!("{:.1}", transition.stats.time);
+            rsx! {
+                div { class: "level-transition",
+                    div { class: "transition-title",
+                        {i18n::format(language, "transition.cleared", &[("level", &transition.cleared_level)])}
+                    }
+                    div { class: "transition-tally",
+                        p { {i18n::format(language, "transition.points", &[("points", &transition.points)])} }
+                        p { {i18n::format(language, "transition.accuracy", &[("percent", &percent)])} }
+                        p { {i18n::format(language, "transition.time", &[("seconds", &seconds)])} }
+                    }
+                }
+            }
+        }
+        TransitionPhase::Intro => rsx! {
+            div { class: "level-transition",
+                div { class: "transition-title",
+                    {i18n::format(language, "transition.wave", &[("level", &transition.next_level())])}
+                }
+            }
+        },
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -55,16 +290,28 @@ mod tests {
         let props1 = GameOverScreenProps {
             score: 100,
             high_score: 500,
+            difficulty: Difficulty::Normal,
+            leaderboard: vec![],
+            key_bindings: KeyBindings::default(),
+            language: Language::English,
         };
 
         let props2 = GameOverScreenProps {
             score: 100,
             high_score: 500,
+            difficulty: Difficulty::Normal,
+            leaderboard: vec![],
+            key_bindings: KeyBindings::default(),
+            language: Language::English,
         };
 
         let props3 = GameOverScreenProps {
             score: 200,
             high_score: 500,
+            difficulty: Difficulty::Normal,
+            leaderboard: vec![],
+            key_bindings: KeyBindings::default(),
+            language: Language::English,
         };
 
         assert_eq!(props1, props2);
@@ -77,6 +324,10 @@ mod tests {
         let props = GameOverScreenProps {
             score: 100,
             high_score: 500,
+            difficulty: Difficulty::Normal,
+            leaderboard: vec![],
+            key_bindings: KeyBindings::default(),
+            language: Language::English,
         };
 
         let cloned_props = props.clone();
@@ -88,7 +339,15 @@ mod tests {
     fn test_start_screen_creation() {
         // This test simply verifies that the StartScreen component can be created
         // without panicking
-        let _ = StartScreen {};
+        let _ = StartScreen(StartScreenProps {
+            combo_scoring: false,
+            arcade_mystery_ship: false,
+            difficulty: Difficulty::Normal,
+            adaptive_difficulty: false,
+            pointer_control: false,
+            key_bindings: KeyBindings::default(),
+            language: Language::English,
+        });
 
         // If we get here, the test passes
         assert!(true);
@@ -100,6 +359,10 @@ mod tests {
         let props = GameOverScreenProps {
             score: 100,
             high_score: 500,
+            difficulty: Difficulty::Normal,
+            leaderboard: vec![],
+            key_bindings: KeyBindings::default(),
+            language: Language::English,
         };
 
         // This test verifies that the GameOverScreen component can be created
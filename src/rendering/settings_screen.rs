@@ -0,0 +1,48 @@
+use crate::game::settings::{Settings, SettingsMenu, SettingsRow};
+use crate::utils::i18n;
+use dioxus::prelude::*;
+
+/// Displays the Settings screen: every setting with its current value
+#[derive(Props, Clone, PartialEq, Debug)]
+pub struct SettingsScreenProps {
+    pub settings: Settings,
+    pub menu: SettingsMenu,
+}
+
+#[component]
+#[allow(non_snake_case)]
+pub fn SettingsScreen(props: SettingsScreenProps) -> Element {
+    let language = props.settings.language();
+    let rows = SettingsRow::ALL
+        .into_iter()
+        .enumerate()
+        .map(|(index, row)| {
+            let class = if index == props.menu.selected {
+                "settings-row selected"
+            } else {
+                "settings-row"
+            };
+            let value = props.settings.value(row);
+
+            rsx! {
+                div { class,
+                    span { class: "settings-name", {row.label(language)} }
+                    span { class: "settings-value", "{value}" }
+                }
+            }
+        });
+
+    rsx! {
+        div { id: "settings-screen",
+            div { class: "title", {i18n::text(language, "settings.title")} }
+            div { class: "settings-list", {rows} }
+            if let Some(message) = &props.menu.message {
+                p { class: "settings-message", "{message}" }
+            }
+            div { class: "instructions",
+                p { {i18n::text(language, "settings.help_select")} }
+                p { {i18n::text(language, "settings.help_leave")} }
+            }
+        }
+    }
+}
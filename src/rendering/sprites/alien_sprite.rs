@@ -1,14 +1,57 @@
-use crate::game::entities::alien::Alien;
+use crate::game::entities::alien::{Alien, AlienType};
+use crate::game::settings::Theme;
+use crate::rendering::sprites::bitmaps::{BitmapSet, PixelArt};
 use dioxus::prelude::*;
 
+/// What an alien looks like on screen
+/// Only the state that changes the sprite is kept, so the sprite is skipped when
+/// re-rendering the game unless the alien visibly changed: timers counting down
+/// or a dive progressing between formation steps do not re-render it.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct AlienView {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    pub alien_type: AlienType,
+    pub is_alive: bool,
+    /// Which of the two animation frames is shown
+    pub second_frame: bool,
+    pub armored: bool,
+    pub damaged: bool,
+    pub diving: bool,
+    pub hit: bool,
+}
+
+impl AlienView {
+    /// Returns -> the visible state of an alien
+    pub fn of(alien: &Alien) -> Self {
+        Self {
+            x: alien.position.x,
+            y: alien.position.y,
+            width: alien.width,
+            height: alien.height,
+            alien_type: alien.alien_type,
+            is_alive: alien.is_alive,
+            second_frame: alien.animation_frame % 2 == 1,
+            armored: alien.armor > 0,
+            damaged: alien.is_damaged(),
+            diving: alien.is_diving(),
+            hit: alien.hit_flash_timer > 0.0,
+        }
+    }
+}
+
 #[derive(Props, PartialEq, Clone)]
 pub struct AlienSpriteProps {
-    pub alien: Alien,
+    pub view: AlienView,
+    /// The theme whose art is drawn
+    pub theme: Theme,
 }
 
 #[component]
 pub fn AlienSprite(props: AlienSpriteProps) -> Element {
-    let alien = &props.alien;
+    let alien = props.view;
 
     if !alien.is_alive {
         return rsx! {
@@ -17,21 +60,46 @@ pub fn AlienSprite(props: AlienSpriteProps) -> Element {
     }
 
     let alien_class = match alien.alien_type {
-        crate::game::entities::alien::AlienType::Small => "alien-small",
-        crate::game::entities::alien::AlienType::Medium => "alien-medium",
-        crate::game::entities::alien::AlienType::Large => "alien-large",
+        AlienType::Small => "alien-small",
+        AlienType::Medium => "alien-medium",
+        AlienType::Large => "alien-large",
     };
 
-    let frame_class = if alien.animation_frame % 2 == 0 {
-        "frame-1"
-    } else {
-        "frame-2"
-    };
+    let armor_class = if alien.armored { "armored" } else { "" };
+    let damage_class = if alien.damaged { "damaged" } else { "" };
+    let dive_class = if alien.diving { "diving" } else { "" };
+    let hit_class = if alien.hit { "alien-hit" } else { "" };
 
     rsx! {
         div {
-            class: "alien {alien_class} {frame_class}",
-            style: "left: {alien.position.x}px; top: {alien.position.y}px; width: {alien.width}px; height: {alien.height}px;",
+            class: "alien {alien_class} {armor_class} {damage_class} {dive_class} {hit_class}",
+            style: "left: {alien.x}px; top: {alien.y}px; width: {alien.width}px; height: {alien.height}px;",
+            PixelArt { bitmap: BitmapSet::for_theme(props.theme).alien(alien.alien_type, alien.second_frame) }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test that the view only changes when the alien visibly changes
+    #[test]
+    fn test_alien_view_ignores_invisible_state() {
+        let mut alien = Alien::new(10.0, 20.0, AlienType::Small);
+        let view = AlienView::of(&alien);
+
+        alien.animation_frame = 2;
+        assert_eq!(AlienView::of(&alien), view);
+
+        alien.hit_flash_timer = 0.2;
+        let flashing = AlienView::of(&alien);
+        assert_ne!(flashing, view);
+        alien.hit_flash_timer = 0.1;
+        assert_eq!(AlienView::of(&alien), flashing);
+
+        alien.hit_flash_timer = 0.0;
+        alien.animation_frame = 3;
+        assert!(AlienView::of(&alien).second_frame);
+    }
+}
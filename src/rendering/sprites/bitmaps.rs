@@ -0,0 +1,524 @@
+use crate::game::entities::alien::AlienType;
+use crate::game::settings::Theme;
+use dioxus::prelude::*;
+use std::fmt::Write;
+
+/// A pixel-art image, one string per row with `X` for a lit pixel
+/// Bitmaps are drawn stretched over the sprite's box, so the same art is used
+/// at any sprite size and by every renderer.
+#[derive(PartialEq, Debug)]
+pub struct Bitmap {
+    pub rows: &'static [&'static str],
+}
+
+impl Bitmap {
+    /// Returns -> the width in pixels
+    pub fn width(&self) -> usize {
+        self.rows.first().map_or(0, |row| row.len())
+    }
+
+    /// Returns -> the height in pixels
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Builds the SVG path of the lit pixels, one unit per pixel
+    /// Neighbouring pixels on a row are merged into one rectangle.
+    /// # Returns -> SVG path data
+    pub fn path(&self) -> String {
+        let mut path = String::new();
+        for (y, row) in self.rows.iter().enumerate() {
+            let pixels = row.as_bytes();
+            let mut x = 0;
+            while x < pixels.len() {
+                if pixels[x] != b'X' {
+                    x += 1;
+                    continue;
+                }
+                let start = x;
+                while x < pixels.len() && pixels[x] == b'X' {
+                    x += 1;
+                }
+                let _ = write!(path, "M{start} {y}h{}v1h-{}z", x - start, x - start);
+            }
+        }
+        path
+    }
+}
+
+/// The two animation frames of the small alien in the classic theme (the squid)
+#[rustfmt::skip]
+pub const CLASSIC_SMALL_ALIEN: [Bitmap; 2] = [
+    Bitmap {
+        rows: &[
+            "...XX...",
+            "..XXXX..",
+            ".XXXXXX.",
+            "XX.XX.XX",
+            "XXXXXXXX",
+            "..X..X..",
+            ".X.XX.X.",
+            "X.X..X.X",
+        ],
+    },
+    Bitmap {
+        rows: &[
+            "...XX...",
+            "..XXXX..",
+            ".XXXXXX.",
+            "XX.XX.XX",
+            "XXXXXXXX",
+            ".X.XX.X.",
+            "X......X",
+            ".X....X.",
+        ],
+    },
+];
+
+/// The two animation frames of the medium alien in the classic theme (the crab)
+#[rustfmt::skip]
+pub const CLASSIC_MEDIUM_ALIEN: [Bitmap; 2] = [
+    Bitmap {
+        rows: &[
+            "..X.....X..",
+            "...X...X...",
+            "..XXXXXXX..",
+            ".XX.XXX.XX.",
+            "XXXXXXXXXXX",
+            "X.XXXXXXX.X",
+            "X.X.....X.X",
+            "...XX.XX...",
+        ],
+    },
+    Bitmap {
+        rows: &[
+            "..X.....X..",
+            "X..X...X..X",
+            "X.XXXXXXX.X",
+            "XXX.XXX.XXX",
+            "XXXXXXXXXXX",
+            ".XXXXXXXXX.",
+            "..X.....X..",
+            ".X.......X.",
+        ],
+    },
+];
+
+/// The two animation frames of the large alien in the classic theme (the octopus)
+#[rustfmt::skip]
+pub const CLASSIC_LARGE_ALIEN: [Bitmap; 2] = [
+    Bitmap {
+        rows: &[
+            "....XXXX....",
+            ".XXXXXXXXXX.",
+            "XXXXXXXXXXXX",
+            "XXX..XX..XXX",
+            "XXXXXXXXXXXX",
+            "...XX..XX...",
+            "..XX.XX.XX..",
+            "XX........XX",
+        ],
+    },
+    Bitmap {
+        rows: &[
+            "....XXXX....",
+            ".XXXXXXXXXX.",
+            "XXXXXXXXXXXX",
+            "XXX..XX..XXX",
+            "XXXXXXXXXXXX",
+            "..XXX..XXX..",
+            ".XX..XX..XX.",
+            "..XX....XX..",
+        ],
+    },
+];
+
+/// The player's ship in the classic theme, and the wreck shown while it has just been hit
+#[rustfmt::skip]
+pub const CLASSIC_PLAYER: [Bitmap; 2] = [
+    Bitmap {
+        rows: &[
+            "......X......",
+            ".....XXX.....",
+            ".....XXX.....",
+            ".XXXXXXXXXXX.",
+            "XXXXXXXXXXXXX",
+            "XXXXXXXXXXXXX",
+            "XXXXXXXXXXXXX",
+            "XXXXXXXXXXXXX",
+        ],
+    },
+    Bitmap {
+        rows: &[
+            "....X....X...",
+            "..X....X.....",
+            "....X.X..X.X.",
+            "X..XX..X.....",
+            "..XXXXX.X..X.",
+            ".XXXXXXXXX...",
+            "XXXXXXXXXXX.X",
+            "XXXXXXXXXXXXX",
+        ],
+    },
+];
+
+/// The mystery ship in the classic theme, with its lights in two positions
+#[rustfmt::skip]
+pub const CLASSIC_MYSTERY_SHIP: [Bitmap; 2] = [
+    Bitmap {
+        rows: &[
+            ".....XXXXXX.....",
+            "...XXXXXXXXXX...",
+            "..XXXXXXXXXXXX..",
+            ".XX.XX.XX.XX.XX.",
+            "XXXXXXXXXXXXXXXX",
+            "..XXX..XX..XXX..",
+            "...X........X...",
+        ],
+    },
+    Bitmap {
+        rows: &[
+            ".....XXXXXX.....",
+            "...XXXXXXXXXX...",
+            "..XXXXXXXXXXXX..",
+            ".X.XX.XX.XX.XX.X",
+            "XXXXXXXXXXXXXXXX",
+            "..XXX..XX..XXX..",
+            "...X........X...",
+        ],
+    },
+];
+
+/// The two animation frames of the small alien in the standard and neon themes
+#[rustfmt::skip]
+pub const STANDARD_SMALL_ALIEN: [Bitmap; 2] = [
+    Bitmap {
+        rows: &[
+            "..XXXXXXXX..",
+            ".XXXXXXXXXX.",
+            "XXXXXXXXXXXX",
+            "XXX..XX..XXX",
+            "XXXXXXXXXXXX",
+            ".XXXXXXXXXX.",
+            "..XXX..XXX..",
+            ".....XX.....",
+        ],
+    },
+    Bitmap {
+        rows: &[
+            "..XXXXXXXX..",
+            ".XXXXXXXXXX.",
+            "XXXXXXXXXXXX",
+            "XXX..XX..XXX",
+            "XXXXXXXXXXXX",
+            ".XXXXXXXXXX.",
+            "..XXXXXXXX..",
+            "....XXXX....",
+        ],
+    },
+];
+
+/// The two animation frames of the medium alien in the standard and neon themes
+#[rustfmt::skip]
+pub const STANDARD_MEDIUM_ALIEN: [Bitmap; 2] = [
+    Bitmap {
+        rows: &[
+            "..XXXXXXXX..",
+            ".XXXXXXXXXX.",
+            "XXXXXXXXXXXX",
+            "XX..XXXX..XX",
+            "XXXXXXXXXXXX",
+            "XXXXXXXXXXXX",
+            ".XX......XX.",
+            "XX........XX",
+        ],
+    },
+    Bitmap {
+        rows: &[
+            "..XXXXXXXX..",
+            ".XXXXXXXXXX.",
+            "XXXXXXXXXXXX",
+            "XX..XXXX..XX",
+            "XXXXXXXXXXXX",
+            "XXXXXXXXXXXX",
+            "..XXXXXXXX..",
+            "....XXXX....",
+        ],
+    },
+];
+
+/// The two animation frames of the large alien in the standard and neon themes
+#[rustfmt::skip]
+pub const STANDARD_LARGE_ALIEN: [Bitmap; 2] = [
+    Bitmap {
+        rows: &[
+            ".....XX.....",
+            "...XXXXXX...",
+            ".XXXXXXXXXX.",
+            "XXX.XXXX.XXX",
+            "XXXXXXXXXXXX",
+            "..XXXXXXXX..",
+            ".XXXXXXXXXX.",
+            "XXXXXXXXXXXX",
+        ],
+    },
+    Bitmap {
+        rows: &[
+            ".....XX.....",
+            "...XXXXXX...",
+            ".XXXXXXXXXX.",
+            "XXX.XXXX.XXX",
+            "XXXXXXXXXXXX",
+            "..XXXXXXXX..",
+            ".XXXXXXXXXX.",
+            "...XXXXXX...",
+        ],
+    },
+];
+
+/// The player's ship in the standard theme, and the wreck shown while it has just been hit
+#[rustfmt::skip]
+pub const STANDARD_PLAYER: [Bitmap; 2] = [
+    Bitmap {
+        rows: &[
+            "......X......",
+            ".....XXX.....",
+            "....XXXXX....",
+            "...XXXXXXX...",
+            "...XXXXXXX...",
+            "..XXXXXXXXX..",
+            ".XXXXXXXXXXX.",
+            "XXXXXXXXXXXXX",
+        ],
+    },
+    Bitmap {
+        rows: &[
+            "......X...X..",
+            ".X...X.......",
+            "....X.X..X...",
+            "..X..XXX.....",
+            "...XX.X.X.X..",
+            "..XXXXX.XX...",
+            ".XXXXXXXXXX.X",
+            "XXXXXXXXXXXXX",
+        ],
+    },
+];
+
+/// The mystery ship in the standard and neon themes, with its lights in two positions
+#[rustfmt::skip]
+pub const STANDARD_MYSTERY_SHIP: [Bitmap; 2] = [
+    Bitmap {
+        rows: &[
+            ".....XXXXXX.....",
+            "...XXXXXXXXXX...",
+            ".XXXXXXXXXXXXXX.",
+            "X.XXX.XXXX.XXX.X",
+            "XXXXXXXXXXXXXXXX",
+            ".XXXXXXXXXXXXXX.",
+            "...XXXXXXXXXX...",
+            ".....XXXXXX.....",
+        ],
+    },
+    Bitmap {
+        rows: &[
+            ".....XXXXXX.....",
+            "...XXXXXXXXXX...",
+            ".XXXXXXXXXXXXXX.",
+            "XXX.XXX..XXX.XXX",
+            "XXXXXXXXXXXXXXXX",
+            ".XXXXXXXXXXXXXX.",
+            "...XXXXXXXXXX...",
+            ".....XXXXXX.....",
+        ],
+    },
+];
+
+/// The player's sleek ship in the neon theme, and the wreck shown while it has just been hit
+#[rustfmt::skip]
+pub const NEON_PLAYER: [Bitmap; 2] = [
+    Bitmap {
+        rows: &[
+            "......X......",
+            "......X......",
+            ".....XXX.....",
+            ".....XXX.....",
+            "....XXXXX....",
+            "...XXXXXXX...",
+            ".XXXXXXXXXXX.",
+            "XXXXXXXXXXXXX",
+            "XXXX.....XXXX",
+            "XX.........XX",
+        ],
+    },
+    Bitmap {
+        rows: &[
+            "...X......X..",
+            "......X......",
+            ".X...X.X.....",
+            ".....X.X..X..",
+            "..X.XX.XX....",
+            "...XXXXXXX...",
+            ".XXXX.XXXXXX.",
+            "XXXXXXX.XXXXX",
+            "XXX......XXXX",
+            "XX.........XX",
+        ],
+    },
+];
+
+/// The sprite art of one theme
+#[derive(PartialEq, Debug)]
+pub struct BitmapSet {
+    pub small_alien: &'static [Bitmap; 2],
+    pub medium_alien: &'static [Bitmap; 2],
+    pub large_alien: &'static [Bitmap; 2],
+    pub player: &'static [Bitmap; 2],
+    pub mystery_ship: &'static [Bitmap; 2],
+}
+
+/// Smooth rounded aliens and an arrowhead ship
+pub static STANDARD: BitmapSet = BitmapSet {
+    small_alien: &STANDARD_SMALL_ALIEN,
+    medium_alien: &STANDARD_MEDIUM_ALIEN,
+    large_alien: &STANDARD_LARGE_ALIEN,
+    player: &STANDARD_PLAYER,
+    mystery_ship: &STANDARD_MYSTERY_SHIP,
+};
+
+/// The squid, crab and octopus of the 1978 cabinet
+pub static CLASSIC: BitmapSet = BitmapSet {
+    small_alien: &CLASSIC_SMALL_ALIEN,
+    medium_alien: &CLASSIC_MEDIUM_ALIEN,
+    large_alien: &CLASSIC_LARGE_ALIEN,
+    player: &CLASSIC_PLAYER,
+    mystery_ship: &CLASSIC_MYSTERY_SHIP,
+};
+
+/// The standard aliens with a sleek swept-wing ship
+pub static NEON: BitmapSet = BitmapSet {
+    small_alien: &STANDARD_SMALL_ALIEN,
+    medium_alien: &STANDARD_MEDIUM_ALIEN,
+    large_alien: &STANDARD_LARGE_ALIEN,
+    player: &NEON_PLAYER,
+    mystery_ship: &STANDARD_MYSTERY_SHIP,
+};
+
+impl BitmapSet {
+    /// Returns -> the sprite art of a theme
+    pub fn for_theme(theme: Theme) -> &'static BitmapSet {
+        match theme {
+            Theme::Standard => &STANDARD,
+            Theme::Classic => &CLASSIC,
+            Theme::Neon => &NEON,
+        }
+    }
+
+    /// Returns -> the bitmap for an alien type and animation frame
+    /// # Arguments
+    /// * `alien_type` - The type of alien
+    /// * `second_frame` - Whether the second animation frame is shown
+    pub fn alien(&self, alien_type: AlienType, second_frame: bool) -> &'static Bitmap {
+        let frames = match alien_type {
+            AlienType::Small => self.small_alien,
+            AlienType::Medium => self.medium_alien,
+            AlienType::Large => self.large_alien,
+        };
+        &frames[second_frame as usize]
+    }
+
+    /// Returns -> the player's ship, or its wreck while it has just been hit
+    pub fn player(&self, wrecked: bool) -> &'static Bitmap {
+        &self.player[wrecked as usize]
+    }
+
+    /// Returns -> the mystery ship as it looks at a position; the lights move as the ship flies
+    pub fn mystery_ship(&self, x: f32) -> &'static Bitmap {
+        &self.mystery_ship[((x / 16.0).floor() as i32 % 2 != 0) as usize]
+    }
+}
+
+#[derive(Props, PartialEq, Clone)]
+pub struct PixelArtProps {
+    pub bitmap: &'static Bitmap,
+}
+
+/// Draws a bitmap as crisp pixels filling its parent, in the parent's text color
+#[component]
+pub fn PixelArt(props: PixelArtProps) -> Element {
+    let bitmap = props.bitmap;
+
+    rsx! {
+        svg {
+            class: "pixel-art",
+            view_box: "0 0 {bitmap.width()} {bitmap.height()}",
+            preserve_aspect_ratio: "none",
+            shape_rendering: "crispEdges",
+            path { d: bitmap.path() }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test that every bitmap is rectangular and both frames are the same size
+    #[test]
+    fn test_bitmaps_are_rectangular() {
+        for frames in [&STANDARD, &CLASSIC, &NEON].iter().flat_map(|set| {
+            [
+                set.small_alien,
+                set.medium_alien,
+                set.large_alien,
+                set.player,
+                set.mystery_ship,
+            ]
+        }) {
+            for bitmap in frames.iter() {
+                assert!(bitmap.rows.iter().all(|row| row.len() == bitmap.width()));
+                assert!(bitmap
+                    .rows
+                    .iter()
+                    .all(|row| row.chars().all(|c| c == 'X' || c == '.')));
+            }
+            assert_eq!(frames[0].width(), frames[1].width());
+            assert_eq!(frames[0].height(), frames[1].height());
+            assert_ne!(frames[0], frames[1]);
+        }
+    }
+
+    // Test that lit pixels on a row are merged into runs
+    #[test]
+    fn test_path() {
+        let bitmap = Bitmap {
+            rows: &["XX.X", "...."],
+        };
+
+        assert_eq!(bitmap.path(), "M0 0h2v1h-2zM3 0h1v1h-1z");
+    }
+
+    // Test that each theme picks its own art
+    #[test]
+    fn test_theme_bitmaps() {
+        let classic = BitmapSet::for_theme(Theme::Classic);
+        assert_eq!(
+            classic.alien(AlienType::Large, true),
+            &CLASSIC_LARGE_ALIEN[1]
+        );
+        assert_eq!(classic.player(true), &CLASSIC_PLAYER[1]);
+
+        let standard = BitmapSet::for_theme(Theme::Standard);
+        let neon = BitmapSet::for_theme(Theme::Neon);
+        assert_ne!(
+            standard.alien(AlienType::Small, false),
+            classic.alien(AlienType::Small, false)
+        );
+        assert_eq!(
+            neon.alien(AlienType::Small, false),
+            standard.alien(AlienType::Small, false)
+        );
+        assert_ne!(neon.player(false), standard.player(false));
+        assert_ne!(standard.mystery_ship(0.0), standard.mystery_ship(16.0));
+    }
+}
@@ -0,0 +1,70 @@
+use crate::game::entities::boss::{Boss, BossPhase};
+use dioxus::prelude::*;
+
+#[derive(Props, PartialEq, Clone)]
+pub struct BossSpriteProps {
+    pub boss: Boss,
+}
+
+/// Renders the boss as its remaining armor plates and weak points
+/// The boss is tinted by its attack phase and flashes when hit.
+#[component]
+#[allow(non_snake_case)]
+pub fn BossSprite(props: BossSpriteProps) -> Element {
+    let boss = &props.boss;
+
+    let phase_class = match boss.phase {
+        BossPhase::Sweep => "phase-sweep",
+        BossPhase::Barrage => "phase-barrage",
+        BossPhase::Enraged => "phase-enraged",
+    };
+    let flash_class = if boss.hit_flash_timer > 0.0 {
+        "boss-hit"
+    } else {
+        ""
+    };
+
+    rsx! {
+        div {
+            class: "boss {phase_class} {flash_class}",
+            style: "left: {boss.position.x}px; top: {boss.position.y}px; width: {boss.width}px; height: {boss.height}px;",
+            {
+                boss.segments.iter().filter(|s| !s.is_destroyed()).map(|segment| {
+                    let kind = if segment.is_weak_point { "weak-point" } else { "armor" };
+                    let damaged = if segment.hit_points < segment.max_hit_points {
+                        "damaged"
+                    } else {
+                        ""
+                    };
+                    rsx! {
+                        div {
+                            class: "boss-segment {kind} {damaged}",
+                            style: "left: {segment.offset.x}px; top: {segment.offset.y}px; width: {segment.width}px; height: {segment.height}px;",
+                        }
+                    }
+                })
+            }
+        }
+    }
+}
+
+#[derive(Props, PartialEq, Clone)]
+pub struct BossHealthBarProps {
+    pub health: f32,
+}
+
+/// Renders the boss's remaining health as a bar at the top of the playfield
+#[component]
+#[allow(non_snake_case)]
+pub fn BossHealthBar(props: BossHealthBarProps) -> Element {
+    let percent = (props.health * 100.0).clamp(0.0, 100.0);
+
+    rsx! {
+        div { class: "boss-health",
+            div { class: "boss-health-label", "BOSS" }
+            div { class: "boss-health-track",
+                div { class: "boss-health-fill", style: "width: {percent}%;" }
+            }
+        }
+    }
+}
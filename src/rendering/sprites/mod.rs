@@ -1,4 +1,6 @@
 pub mod alien_sprite;
+pub mod bitmaps;
+pub mod boss_sprite;
 pub mod mystery_ship_sprite;
 pub mod player_sprite;
 pub mod projectile_sprite;
@@ -1,9 +1,13 @@
 use crate::game::entities::mystery_ship::MysteryShip;
+use crate::game::settings::Theme;
+use crate::rendering::sprites::bitmaps::{BitmapSet, PixelArt};
 use dioxus::prelude::*;
 
 #[derive(Props, PartialEq, Clone)]
 pub struct MysteryShipSpriteProps {
     pub ship: MysteryShip,
+    /// The theme whose art is drawn
+    pub theme: Theme,
 }
 
 #[component]
@@ -20,6 +24,7 @@ pub fn MysteryShipSprite(props: MysteryShipSpriteProps) -> Element {
         div {
             class: "mystery-ship",
             style: "left: {ship.position.x}px; top: {ship.position.y}px; width: {ship.width}px; height: {ship.height}px;",
+            PixelArt { bitmap: BitmapSet::for_theme(props.theme).mystery_ship(ship.position.x) }
         }
     }
 }
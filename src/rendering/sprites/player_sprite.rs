@@ -1,9 +1,13 @@
 use crate::game::entities::player::Player;
+use crate::game::settings::Theme;
+use crate::rendering::sprites::bitmaps::{BitmapSet, PixelArt};
 use dioxus::prelude::*;
 
 #[derive(Props, PartialEq, Clone)]
 pub struct PlayerShipProps {
     pub player: Player,
+    /// The theme whose art is drawn
+    pub theme: Theme,
 }
 
 #[component]
@@ -18,8 +22,7 @@ pub fn PlayerShip(props: PlayerShipProps) -> Element {
                    top: {player.position.y}px; 
                    width: {player.width}px; 
                    height: {player.height}px;",
-            div { class: "player-cannon" }
-            div { class: "player-body" }
+            PixelArt { bitmap: BitmapSet::for_theme(props.theme).player(player.is_wrecked()) }
         }
     }
 }
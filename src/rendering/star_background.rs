@@ -1,54 +1,56 @@
+use crate::game::starfield::{Star, Starfield};
 use dioxus::prelude::*;
-use rand::Rng;
 
-/// Represents a single star in the background starfield
-
-#[derive(Clone)]
-pub struct Star {
-    pub x: f32,
-    pub y: f32,
-    pub opacity: f32,
+#[derive(Props, PartialEq, Clone)]
+pub struct StarBackgroundProps {
+    pub starfield: Starfield,
+    /// Show the stars at a steady brightness instead of twinkling
+    pub reduced_motion: bool,
 }
 
-/// Generates a collection of randomly positioned stars
-/// # Arguments
-/// * `count` - The number of stars to generate
-/// # Returns -> A vector containing the specified number of randomly positioned stars
-
-pub fn generate_stars(count: usize) -> Vec<Star> {
-    let mut rng = rand::thread_rng();
-    let mut stars = Vec::with_capacity(count);
-    for _ in 0..count {
-        stars.push(Star {
-            x: rng.gen::<f32>() * 100.0,
-            y: rng.gen::<f32>() * 100.0,
-            opacity: rng.gen::<f32>() * 0.8 + 0.2,
-        });
-    }
-    stars
-}
-
-/// Renders a starry background for the game
-
-#[allow(non_snake_case)]
-pub fn StarBackground() -> Element {
-    let stars = use_signal(|| generate_stars(100));
+/// Renders the parallax starfield behind the playfield
+/// Each layer is its own container so nearer layers can draw bigger stars, and
+/// the warp strength is passed to the CSS to stretch the stars into streaks.
+#[component]
+pub fn StarBackground(props: StarBackgroundProps) -> Element {
+    let starfield = &props.starfield;
+    let density = starfield.density();
 
     rsx! {
-        div { id: "starfield",
-            for star in stars().iter() {
+        div { id: "starfield", style: "--warp: {starfield.warp};",
+            for (depth, layer) in starfield.layers.iter().enumerate() {
+                div { key: "{depth}", class: "star-layer star-layer-{depth}",
+                    for star in layer.visible(density) {
+                        div {
+                            class: "star",
+                            style: "left: {star.x}%; top: {star.y}%; opacity: {star_opacity(star, starfield.time, props.reduced_motion)};",
+                        }
+                    }
+                }
+            }
+            for shooting_star in starfield.shooting_stars.iter() {
                 div {
-                    class: "star",
-                    style: "left: {star.x}%; top: {star.y}%; opacity: {star.opacity};",
+                    class: "shooting-star",
+                    style: "left: {shooting_star.x}%; top: {shooting_star.y}%; opacity: {shooting_star.opacity()};",
                 }
             }
         }
     }
 }
 
+/// Returns -> how bright a star is drawn, twinkling unless reduced motion is on
+fn star_opacity(star: &Star, time: f64, reduced_motion: bool) -> f32 {
+    if reduced_motion {
+        star.opacity
+    } else {
+        star.brightness(time)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::game::starfield::generate_stars;
 
     // Test that stars are generated with the correct count
     #[test]
@@ -80,6 +82,7 @@ mod tests {
             x: 50.0,
             y: 75.0,
             opacity: 0.5,
+            twinkle_phase: 1.0,
         };
 
         let cloned_star = star.clone();
@@ -89,6 +92,20 @@ mod tests {
         assert_eq!(star.opacity, cloned_star.opacity);
     }
 
+    // Test that stars only twinkle when reduced motion is off
+    #[test]
+    fn test_star_opacity() {
+        let star = Star {
+            x: 0.0,
+            y: 0.0,
+            opacity: 0.5,
+            twinkle_phase: 0.0,
+        };
+
+        assert_eq!(star_opacity(&star, 0.5, true), 0.5);
+        assert_eq!(star_opacity(&star, 0.5, false), star.brightness(0.5));
+    }
+
     // Test that stars are randomly distributed
     #[test]
     fn test_stars_randomness() {
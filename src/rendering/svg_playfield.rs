@@ -0,0 +1,276 @@
+use crate::game::entities::alien::{Alien, AlienType};
+use crate::game::entities::shield::Shield;
+use crate::game::state::GameState;
+use crate::rendering::sprites::bitmaps::{Bitmap, BitmapSet};
+use crate::rendering::sprites::boss_sprite::BossHealthBar;
+use dioxus::prelude::*;
+use std::fmt::Write;
+
+/// Whether the playfield is drawn as one SVG element (builds with the `svg-renderer` feature)
+/// instead of one DOM node per sprite
+pub const SVG_RENDERER: bool = cfg!(feature = "svg-renderer");
+
+/// An outline as (x, y) fractions of the sprite's width and height
+type Shape = &'static [(f32, f32)];
+
+/// The alien projectile, matching the CSS sprite
+const ALIEN_PROJECTILE: Shape = &[(0.0, 0.0), (1.0, 0.0), (0.5, 1.0)];
+
+/// Builds the `points` attribute of an SVG polygon
+/// # Arguments
+/// * `shape` - The outline, as fractions of the sprite's size
+/// * `x` - Left edge of the sprite
+/// * `y` - Top edge of the sprite
+/// * `width` - Width of the sprite
+/// * `height` - Height of the sprite
+/// # Returns -> The outline in playfield coordinates, e.g. `"0,10 5,0 10,10"`
+pub fn polygon_points(shape: &[(f32, f32)], x: f32, y: f32, width: f32, height: f32) -> String {
+    let mut points = String::new();
+    for (fx, fy) in shape {
+        if !points.is_empty() {
+            points.push(' ');
+        }
+        let _ = write!(points, "{},{}", x + fx * width, y + fy * height);
+    }
+    points
+}
+
+/// Builds the transform that stretches a bitmap, drawn one unit per pixel, over a sprite
+/// # Arguments
+/// * `bitmap` - The bitmap to draw
+/// * `x` - Left edge of the sprite
+/// * `y` - Top edge of the sprite
+/// * `width` - Width of the sprite
+/// * `height` - Height of the sprite
+/// # Returns -> The SVG `transform` attribute
+pub fn bitmap_transform(bitmap: &Bitmap, x: f32, y: f32, width: f32, height: f32) -> String {
+    let scale_x = width / bitmap.width().max(1) as f32;
+    let scale_y = height / bitmap.height().max(1) as f32;
+    format!("translate({x} {y}) scale({scale_x} {scale_y})")
+}
+
+/// Builds the path of a shield's remaining segments
+/// Neighbouring segments on a row are merged into one rectangle, so a shield
+/// is a single element however many segments it has.
+/// # Arguments
+/// * `shield` - The shield to draw
+/// # Returns -> SVG path data, empty when every segment is destroyed
+pub fn shield_path(shield: &Shield) -> String {
+    let rows = shield.segments.len().max(1);
+    let segment_height = shield.height / rows as f32;
+    let mut path = String::new();
+
+    for (row, segments) in shield.segments.iter().enumerate() {
+        let segment_width = shield.width / segments.len().max(1) as f32;
+        let y = shield.position.y + row as f32 * segment_height;
+        let mut column = 0;
+
+        while column < segments.len() {
+            if !segments[column] {
+                column += 1;
+                continue;
+            }
+            let start = column;
+            while column < segments.len() && segments[column] {
+                column += 1;
+            }
+            let x = shield.position.x + start as f32 * segment_width;
+            let width = (column - start) as f32 * segment_width;
+            let _ = write!(path, "M{x} {y}h{width}v{segment_height}h-{width}z");
+        }
+    }
+    path
+}
+
+/// Returns -> the class list of an alien's sprite, mirroring the CSS sprite's classes
+fn alien_class(alien: &Alien) -> String {
+    let mut class = match alien.alien_type {
+        AlienType::Small => "svg-alien-small",
+        AlienType::Medium => "svg-alien-medium",
+        AlienType::Large => "svg-alien-large",
+    }
+    .to_string();
+    if alien.armor > 0 {
+        class.push_str(" armored");
+    }
+    if alien.is_damaged() {
+        class.push_str(" damaged");
+    }
+    if alien.hit_flash_timer > 0.0 {
+        class.push_str(" alien-hit");
+    }
+    class
+}
+
+/// Draws the whole playfield into one SVG element
+/// Every sprite is a single SVG shape, drawn from the same bitmaps and colored by
+/// the same CSS variables as the DOM sprites so themes and palettes still apply.
+/// # Arguments
+/// * `state` - The game state to draw
+/// # Returns -> The SVG element, plus the boss health bar when a boss is present
+pub fn svg_playfield(state: &GameState) -> Element {
+    let bitmaps = BitmapSet::for_theme(state.settings.theme);
+    let aliens = state
+        .alien_formation
+        .aliens
+        .iter()
+        .filter(|alien| alien.is_alive)
+        .map(|alien| {
+            let bitmap = bitmaps.alien(alien.alien_type, alien.animation_frame % 2 == 1);
+            rsx! {
+                g {
+                    key: "{alien.id}",
+                    class: alien_class(alien),
+                    transform: bitmap_transform(
+                        bitmap,
+                        alien.position.x,
+                        alien.position.y,
+                        alien.width,
+                        alien.height,
+                    ),
+                    path { d: bitmap.path() }
+                }
+            }
+        });
+
+    let shields = state.shields.iter().map(|shield| {
+        rsx! {
+            path { key: "{shield.id}", class: "svg-shield", d: shield_path(shield) }
+        }
+    });
+
+    let projectiles = state
+        .player_projectiles
+        .iter()
+        .chain(state.alien_projectiles.iter())
+        .map(|projectile| {
+            let (x, y) = (projectile.position.x, projectile.position.y);
+            let (width, height) = (projectile.width, projectile.height);
+            if projectile.is_player_projectile {
+                rsx! {
+                    rect {
+                        key: "{projectile.id}",
+                        class: "svg-player-projectile",
+                        x: "{x}",
+                        y: "{y}",
+                        width: "{width}",
+                        height: "{height}",
+                    }
+                }
+            } else {
+                rsx! {
+                    polygon {
+                        key: "{projectile.id}",
+                        class: "svg-alien-projectile",
+                        points: polygon_points(ALIEN_PROJECTILE, x, y, width, height),
+                    }
+                }
+            }
+        });
+
+    let player = &state.player;
+    let player_left = player.position.x - player.width / 2.0;
+    let player_class = if player.is_hit { "player-hit" } else { "" };
+    let player_bitmap = bitmaps.player(player.is_wrecked());
+
+    rsx! {
+        svg {
+            class: "svg-playfield",
+            width: "100%",
+            height: "100%",
+            shape_rendering: "crispEdges",
+            {shields}
+            {aliens}
+            if let Some(ship) = state.mystery_ship.as_ref().filter(|ship| ship.active) {
+                path {
+                    class: "svg-mystery-ship",
+                    transform: bitmap_transform(
+                        bitmaps.mystery_ship(ship.position.x),
+                        ship.position.x,
+                        ship.position.y,
+                        ship.width,
+                        ship.height,
+                    ),
+                    d: bitmaps.mystery_ship(ship.position.x).path(),
+                }
+            }
+            if let Some(boss) = &state.boss {
+                g { class: if boss.hit_flash_timer > 0.0 { "boss-hit" } else { "" },
+                    for segment in boss.segments.iter().filter(|s| !s.is_destroyed()) {
+                        rect {
+                            class: if segment.is_weak_point { "svg-boss-weak-point" } else { "svg-boss-armor" },
+                            x: "{boss.position.x + segment.offset.x}",
+                            y: "{boss.position.y + segment.offset.y}",
+                            width: "{segment.width}",
+                            height: "{segment.height}",
+                        }
+                    }
+                }
+            }
+            {projectiles}
+            g {
+                class: "svg-player {player_class}",
+                transform: bitmap_transform(
+                    player_bitmap,
+                    player_left,
+                    player.position.y,
+                    player.width,
+                    player.height,
+                ),
+                path { d: player_bitmap.path() }
+            }
+        }
+        if let Some(boss) = &state.boss {
+            BossHealthBar { health: boss.health_fraction() }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::entities::shield::ShieldType;
+
+    // Test that outlines are scaled and moved onto the sprite
+    #[test]
+    fn test_polygon_points() {
+        assert_eq!(
+            polygon_points(ALIEN_PROJECTILE, 10.0, 20.0, 4.0, 8.0),
+            "10,20 14,20 12,28"
+        );
+    }
+
+    // Test that bitmaps are stretched over the sprite
+    #[test]
+    fn test_bitmap_transform() {
+        let bitmap = Bitmap {
+            rows: &["X.X.", "....", ".XX."],
+        };
+
+        assert_eq!(
+            bitmap_transform(&bitmap, 10.0, 20.0, 40.0, 30.0),
+            "translate(10 20) scale(10 10)"
+        );
+    }
+
+    // Test that a shield is one path and shrinks as segments are destroyed
+    #[test]
+    fn test_shield_path() {
+        let mut shield = Shield::new(0.0, 0.0, ShieldType::UppercaseO);
+        let full = shield_path(&shield);
+        let active: usize = shield
+            .segments
+            .iter()
+            .map(|row| row.iter().filter(|s| **s).count())
+            .sum();
+        assert!(full.matches('M').count() < active);
+
+        shield.segments = vec![vec![true, true, false, true]];
+        shield.width = 40.0;
+        shield.height = 10.0;
+        assert_eq!(shield_path(&shield), "M0 0h20v10h-20zM30 0h10v10h-10z");
+
+        shield.segments = vec![vec![false; 4]];
+        assert!(shield_path(&shield).is_empty());
+    }
+}
@@ -0,0 +1,92 @@
+use crate::input::bindings::Action;
+use crate::utils::i18n::{self, Language};
+use dioxus::events::Key;
+use dioxus::prelude::*;
+
+/// Whether the on-screen touch controls are shown (builds with the `mobile` feature)
+pub const TOUCH_CONTROLS: bool = cfg!(feature = "mobile");
+
+/// On-screen buttons for playing on a touch screen
+/// Holding the left or right zone moves the ship, the fire button shoots and
+/// the pause button pauses. Each touch is reported as the matching action so
+/// touch input goes through the same path as the keyboard.
+#[derive(Props, Clone, PartialEq)]
+pub struct TouchControlsProps {
+    /// Called with the action and whether it was pressed (true) or released (false)
+    pub on_action: EventHandler<(Action, bool)>,
+    pub language: Language,
+}
+
+#[component]
+#[allow(non_snake_case)]
+pub fn TouchControls(props: TouchControlsProps) -> Element {
+    let on_action = props.on_action;
+    let fire = i18n::text(props.language, "touch.fire");
+    let button = move |action: Action, class: &str, label: &str| {
+        rsx! {
+            div {
+                class: "touch-button {class}",
+                ontouchstart: move |event: TouchEvent| {
+                    event.prevent_default();
+                    event.stop_propagation();
+                    on_action.call((action, true));
+                },
+                ontouchend: move |event: TouchEvent| {
+                    event.prevent_default();
+                    on_action.call((action, false));
+                },
+                ontouchcancel: move |_| on_action.call((action, false)),
+                "{label}"
+            }
+        }
+    };
+
+    rsx! {
+        div { id: "touch-controls",
+            {button(Action::MoveLeft, "touch-left", "◀")}
+            {button(Action::MoveRight, "touch-right", "▶")}
+            {button(Action::Pause, "touch-pause", "II")}
+            {button(Action::Fire, "touch-fire", &fire)}
+        }
+    }
+}
+
+/// On-screen buttons for entering initials on a touch screen
+/// Each button types the key that does the same on a keyboard: Backspace goes
+/// back a letter, Up and Down change the letter and Enter moves on, submitting
+/// the initials on the last letter.
+#[derive(Props, Clone, PartialEq)]
+pub struct InitialsTouchControlsProps {
+    /// Called with the key a button stands for
+    pub on_key: EventHandler<Key>,
+    pub language: Language,
+}
+
+#[component]
+#[allow(non_snake_case)]
+pub fn InitialsTouchControls(props: InitialsTouchControlsProps) -> Element {
+    let on_key = props.on_key;
+    let ok = i18n::text(props.language, "touch.ok");
+    let button = move |key: Key, label: &str| {
+        rsx! {
+            div {
+                class: "touch-button",
+                ontouchstart: move |event: TouchEvent| {
+                    event.prevent_default();
+                    event.stop_propagation();
+                    on_key.call(key.clone());
+                },
+                "{label}"
+            }
+        }
+    };
+
+    rsx! {
+        div { id: "initials-touch-controls",
+            {button(Key::Backspace, "◀")}
+            {button(Key::ArrowUp, "▲")}
+            {button(Key::ArrowDown, "▼")}
+            {button(Key::Enter, &ok)}
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::OnceLock;
+
+/// The directory the message catalogs are read from when the game starts
+const CATALOG_DIR: &str = "assets/i18n";
+
+/// A language the game's text can be shown in
+/// Each language has a message catalog in `assets/i18n/<code>.toml`. Catalogs are
+/// TOML tables of messages; a message is looked up by its dotted path, e.g.
+/// `start.prompt`. Messages missing from a catalog fall back to English.
+/// On desktop the catalogs are loaded from the asset directory, so a translation
+/// can be changed without rebuilding; the copies built into the game are used
+/// when a file is missing or invalid, and always on the web.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Hash)]
+pub enum Language {
+    #[default]
+    English,
+    French,
+    Spanish,
+}
+
+impl Language {
+    /// Every language, in the order the Settings screen cycles through them
+    pub const ALL: [Language; 3] = [Language::English, Language::French, Language::Spanish];
+
+    /// Returns -> the code the language is saved under and its catalog is named after
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::French => "fr",
+            Language::Spanish => "es",
+        }
+    }
+
+    /// Finds a language by its code
+    /// # Arguments
+    /// * `code` - A language code such as `fr`
+    /// # Returns -> The language, or None if there is no catalog for the code
+    pub fn from_code(code: &str) -> Option<Language> {
+        Self::ALL
+            .into_iter()
+            .find(|language| language.code() == code)
+    }
+
+    /// Returns -> the name of the language, written in that language
+    pub fn name(&self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::French => "Français",
+            Language::Spanish => "Español",
+        }
+    }
+
+    /// Returns -> the copy of the language's catalog built into the game
+    fn embedded_source(&self) -> &'static str {
+        match self {
+            Language::English => include_str!("../../assets/i18n/en.toml"),
+            Language::French => include_str!("../../assets/i18n/fr.toml"),
+            Language::Spanish => include_str!("../../assets/i18n/es.toml"),
+        }
+    }
+
+    /// Returns -> the language's catalog file from the asset directory, if it can be read
+    #[cfg(not(target_arch = "wasm32"))]
+    fn file_source(&self) -> Option<String> {
+        std::fs::read_to_string(format!("{}/{}.toml", CATALOG_DIR, self.code())).ok()
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn file_source(&self) -> Option<String> {
+        None
+    }
+
+    fn catalog(&self) -> &'static Catalog {
+        static CATALOGS: [OnceLock<Catalog>; Language::ALL.len()] =
+            [OnceLock::new(), OnceLock::new(), OnceLock::new()];
+
+        CATALOGS[*self as usize].get_or_init(|| {
+            self.file_source()
+                .and_then(|source| parse_catalog(&source).ok())
+                .unwrap_or_else(|| {
+                    parse_catalog(self.embedded_source()).unwrap_or_else(|e| {
+                        panic!("invalid {} message catalog: {}", self.code(), e)
+                    })
+                })
+        })
+    }
+}
+
+/// Messages of one language, by dotted key
+type Catalog = HashMap<String, String>;
+
+/// Reads a message catalog, flattening nested tables into dotted keys
+/// # Arguments
+/// * `source` - The contents of a catalog file
+/// # Returns -> The messages by key, or the TOML error message
+fn parse_catalog(source: &str) -> Result<Catalog, String> {
+    fn flatten(prefix: &str, table: toml::Table, catalog: &mut Catalog) -> Result<(), String> {
+        for (name, value) in table {
+            let key = if prefix.is_empty() {
+                name
+            } else {
+                format!("{}.{}", prefix, name)
+            };
+            match value {
+                toml::Value::String(message) => {
+                    catalog.insert(key, message);
+                }
+                toml::Value::Table(table) => flatten(&key, table, catalog)?,
+                _ => return Err(format!("{} is not a message", key)),
+            }
+        }
+        Ok(())
+    }
+
+    let table: toml::Table = toml::from_str(source).map_err(|e| e.message().to_string())?;
+    let mut catalog = Catalog::new();
+    flatten("", table, &mut catalog)?;
+    Ok(catalog)
+}
+
+/// Looks up a message
+/// # Arguments
+/// * `language` - The language to show the message in
+/// * `key` - The dotted key of the message, e.g. `pause.title`
+/// # Returns -> The message, the English message if the language has none,
+/// or the key itself if no catalog has it
+pub fn text(language: Language, key: &str) -> String {
+    language
+        .catalog()
+        .get(key)
+        .or_else(|| Language::English.catalog().get(key))
+        .cloned()
+        .unwrap_or_else(|| key.to_string())
+}
+
+/// Looks up a message and fills in its placeholders
+/// # Arguments
+/// * `language` - The language to show the message in
+/// * `key` - The dotted key of the message
+/// * `args` - Values for the message's `{name}` placeholders
+/// # Returns -> The finished message
+pub fn format(language: Language, key: &str, args: &[(&str, &dyn Display)]) -> String {
+    args.iter()
+        .fold(text(language, key), |message, (name, value)| {
+            message.replace(&format!("{{{}}}", name), &value.to_string())
+        })
+}
+
+/// Returns -> `ON` or `OFF` in the given language
+pub fn on_off(language: Language, on: bool) -> String {
+    text(language, if on { "common.on" } else { "common.off" })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    // Test that every catalog parses and has exactly the messages English has
+    #[test]
+    fn test_catalogs_are_complete() {
+        let english: HashSet<_> = Language::English.catalog().keys().collect();
+
+        for language in Language::ALL {
+            let keys: HashSet<_> = language.catalog().keys().collect();
+            assert_eq!(keys, english, "{} catalog", language.code());
+        }
+    }
+
+    // Test that the built-in catalogs parse and match the files in the asset directory
+    #[test]
+    fn test_embedded_catalogs() {
+        for language in Language::ALL {
+            let embedded = parse_catalog(language.embedded_source()).unwrap();
+            assert_eq!(&embedded, language.catalog(), "{} catalog", language.code());
+        }
+    }
+
+    // Test that messages are looked up, filled in and fall back sensibly
+    #[test]
+    fn test_text_and_format() {
+        assert_eq!(text(Language::French, "pause.title"), "PAUSE");
+        assert_eq!(text(Language::Spanish, "settings.title"), "AJUSTES");
+        assert_eq!(text(Language::French, "no.such.message"), "no.such.message");
+        assert_eq!(
+            format(Language::English, "hud.score", &[("score", &120)]),
+            "Score: 120"
+        );
+        assert_eq!(on_off(Language::Spanish, true), "SÍ");
+    }
+
+    // Test that language codes round trip and unknown codes are rejected
+    #[test]
+    fn test_language_codes() {
+        for language in Language::ALL {
+            assert_eq!(Language::from_code(language.code()), Some(language));
+        }
+        assert_eq!(Language::from_code("xx"), None);
+    }
+
+    // Test that values other than strings are reported
+    #[test]
+    fn test_parse_catalog_errors() {
+        assert!(parse_catalog("[start]\ntitle = 3").is_err());
+        assert_eq!(
+            parse_catalog("[a.b]\nc = \"d\"").unwrap().get("a.b.c"),
+            Some(&"d".to_string())
+        );
+    }
+}
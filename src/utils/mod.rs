@@ -1 +1,3 @@
+pub mod i18n;
 pub mod position;
+pub mod storage;
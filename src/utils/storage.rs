@@ -0,0 +1,59 @@
+use std::io;
+
+/// Saves a named piece of text so it survives restarts
+/// On desktop this is a file in the user's data directory; on the web it is
+/// an entry in localStorage.
+/// # Arguments
+/// * `name` - The file name, e.g. `custom_level.toml`
+/// * `contents` - The text to store
+/// # Returns -> `Ok(())` on success, or the I/O error that occurred
+#[cfg(not(target_arch = "wasm32"))]
+pub fn save(name: &str, contents: &str) -> io::Result<()> {
+    let dir = storage_dir();
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(dir.join(name), contents)
+}
+
+/// Loads a piece of text previously stored with `save`
+/// # Arguments
+/// * `name` - The file name used when saving
+/// # Returns -> The stored text, or an error of kind `NotFound` if nothing was saved
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load(name: &str) -> io::Result<String> {
+    std::fs::read_to_string(storage_dir().join(name))
+}
+
+/// Returns -> the directory the game keeps its files in
+#[cfg(not(target_arch = "wasm32"))]
+fn storage_dir() -> std::path::PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| std::path::PathBuf::from("."))
+        .join("space_invaders")
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn save(name: &str, contents: &str) -> io::Result<()> {
+    local_storage()?
+        .set_item(&storage_key(name), contents)
+        .map_err(|_| io::Error::other("could not write to localStorage"))
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn load(name: &str) -> io::Result<String> {
+    local_storage()?
+        .get_item(&storage_key(name))
+        .map_err(|_| io::Error::other("could not read from localStorage"))?
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("{name} not found")))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> io::Result<web_sys::Storage> {
+    web_sys::window()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .ok_or_else(|| io::Error::other("localStorage is not available"))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn storage_key(name: &str) -> String {
+    format!("space_invaders/{name}")
+}
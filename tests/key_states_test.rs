@@ -3,19 +3,30 @@
 fn test_key_states_with_game_state() {
     use dioxus::events::Key;
     use space_invaders::game::state::GameState;
+    use space_invaders::input::bindings::KeyBindings;
 
     let mut game_state = GameState::default();
+    let bindings = KeyBindings::default();
 
     assert!(!game_state.key_states.left);
     assert!(!game_state.key_states.right);
 
-    game_state.key_states.update_from_key(Key::ArrowLeft, true);
+    game_state
+        .key_states
+        .update(&bindings, &Key::ArrowLeft, true);
+    game_state.key_states.begin_tick();
     assert!(game_state.key_states.left);
 
-    game_state.key_states.update_from_key(Key::ArrowRight, true);
+    game_state
+        .key_states
+        .update(&bindings, &Key::ArrowRight, true);
+    game_state.key_states.begin_tick();
     assert!(game_state.key_states.right);
 
-    game_state.key_states.update_from_key(Key::ArrowLeft, false);
+    game_state
+        .key_states
+        .update(&bindings, &Key::ArrowLeft, false);
+    game_state.key_states.begin_tick();
     assert!(!game_state.key_states.left);
     assert!(game_state.key_states.right);
 }
@@ -24,17 +35,21 @@ fn test_key_states_with_game_state() {
 #[test]
 fn test_key_states_clone_and_equality() {
     use dioxus::events::Key;
+    use space_invaders::input::bindings::KeyBindings;
     use space_invaders::input::key_states::KeyStates;
 
+    let bindings = KeyBindings::default();
     let mut key_states1 = KeyStates::default();
-    key_states1.update_from_key(Key::ArrowLeft, true);
-    key_states1.update_from_key(Key::Shift, true);
+    key_states1.update(&bindings, &Key::ArrowLeft, true);
+    key_states1.update(&bindings, &Key::Shift, true);
+    key_states1.begin_tick();
 
     let key_states2 = key_states1.clone();
 
     assert_eq!(key_states1, key_states2);
 
-    key_states1.update_from_key(Key::ArrowLeft, false);
+    key_states1.update(&bindings, &Key::ArrowLeft, false);
+    key_states1.begin_tick();
 
     assert_ne!(key_states1, key_states2);
 }
@@ -38,3 +38,14 @@ fn test_mystery_ship_speed() {
     let expected_x = 100.0 + (5.0 * 1.0 * dt * 60.0);
     assert_eq!(ship.position.x, expected_x);
 }
+
+// Test that the arcade score table repeats every 15 shots
+#[test]
+fn test_arcade_points_table_repeats() {
+    use space_invaders::game::entities::mystery_ship::{arcade_points, ARCADE_POINTS_TABLE};
+
+    for shots in 0..ARCADE_POINTS_TABLE.len() as u32 {
+        assert_eq!(arcade_points(shots), arcade_points(shots + 15));
+    }
+    assert_eq!(arcade_points(23), 300);
+}
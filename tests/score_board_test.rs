@@ -15,6 +15,9 @@ fn test_score_board_with_game_state() {
         high_score: game_state.high_score,
         lives: game_state.lives,
         level: game_state.level,
+        extra_life_flash: game_state.extra_life_flash_timer > 0.0,
+        combo_multiplier: None,
+        language: game_state.settings.language(),
     };
 
     assert_eq!(props.score, 250);
@@ -28,16 +31,21 @@ fn test_score_board_with_game_state() {
 #[test]
 fn test_score_board_props() {
     use space_invaders::rendering::score_board::ScoreBoardProps;
+    use space_invaders::utils::i18n::Language;
 
     let props = ScoreBoardProps {
         score: 100,
         high_score: 500,
         lives: 3,
         level: 1,
+        extra_life_flash: false,
+        combo_multiplier: Some(2),
+        language: Language::French,
     };
 
     assert_eq!(props.score, 100);
     assert_eq!(props.high_score, 500);
     assert_eq!(props.lives, 3);
     assert_eq!(props.level, 1);
+    assert_eq!(props.combo_multiplier, Some(2));
 }
@@ -1,8 +1,10 @@
 // Test that GameOverScreen correctly displays score values
 #[test]
 fn test_game_over_screen_displays_scores() {
+    use space_invaders::game::difficulty::Difficulty;
     use space_invaders::game::state::GameState;
     use space_invaders::rendering::screens::GameOverScreenProps;
+    use space_invaders::utils::i18n::Language;
 
     let mut game_state = GameState::default();
     game_state.score = 250;
@@ -11,10 +13,17 @@ fn test_game_over_screen_displays_scores() {
     let props = GameOverScreenProps {
         score: game_state.score,
         high_score: game_state.high_score,
+        difficulty: game_state.options.difficulty,
+        leaderboard: game_state.leaderboard.entries.clone(),
+        key_bindings: game_state.key_bindings.clone(),
+        language: game_state.settings.language(),
     };
 
     assert_eq!(props.score, 250);
     assert_eq!(props.high_score, 1000);
+    assert_eq!(props.difficulty, Difficulty::Normal);
+    assert!(props.leaderboard.is_empty());
+    assert_eq!(props.language, Language::English);
 }
 
 // Test that screens integrate with game state transitions
@@ -44,3 +44,21 @@ fn test_shield_degradation() {
 
     assert!(remaining_active_segments < initial_active_segments);
 }
+
+// Test that a damaged shield can be regenerated and partially repaired
+#[test]
+fn test_shield_regeneration_and_repair() {
+    use space_invaders::game::entities::shield::{Shield, ShieldType};
+    let mut shield = Shield::new(100.0, 200.0, ShieldType::UppercaseO);
+
+    shield.check_projectile_collision(150.0, 225.0, 3.0, 15.0);
+    shield.check_projectile_collision(110.0, 205.0, 3.0, 15.0);
+    let destroyed = shield.destroyed_segment_count();
+    assert!(destroyed > 0);
+
+    shield.repair(0);
+    assert_eq!(shield.destroyed_segment_count(), destroyed);
+
+    shield.regenerate();
+    assert_eq!(shield.destroyed_segment_count(), 0);
+}
@@ -1,7 +1,7 @@
 // Test that StarBackground integrates with the game rendering
 #[test]
 fn test_star_background_integration() {
-    use space_invaders::rendering::star_background::generate_stars;
+    use space_invaders::game::starfield::generate_stars;
 
     let stars = generate_stars(100);
 
@@ -27,7 +27,7 @@ fn test_star_background_integration() {
 // Test that StarBackground can be used with different star counts
 #[test]
 fn test_star_background_flexibility() {
-    use space_invaders::rendering::star_background::generate_stars;
+    use space_invaders::game::starfield::generate_stars;
 
     let few_stars = generate_stars(10);
     let many_stars = generate_stars(500);